@@ -1,3 +1,5 @@
+#![allow(clippy::needless_range_loop)]
+
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 use integer_encoding::VarInt;
 use rand::distributions::{Distribution, Standard};
@@ -29,7 +31,7 @@ where
         let mut idx = 0;
         for _ in 0..C {
             if encoded.len() < idx + 16 {
-                encoded.extend(std::iter::repeat(0).take(idx + 11 - encoded.len()))
+                encoded.resize(idx + 11, 0)
             }
             let len = rng.gen::<T>().encode_var(&mut encoded[idx..]);
             idx += len;
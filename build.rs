@@ -14,6 +14,7 @@ enum PdepPerf {
 }
 
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[allow(unused_unsafe)] // __cpuid is safe on newer compilers
 fn pdep_speed() -> PdepPerf {
     let leaf0 = unsafe { __cpuid(0) };
     let mut buf = Vec::with_capacity(12);
@@ -55,6 +56,8 @@ fn pdep_speed() -> PdepPerf {
 }
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(fast_pdep, very_fast_pdep, rustc_nightly)");
+
     if std::env::var(
         "CARGO_FEATURE_DANGEROUSLY_FORCE_ENABLE_PDEP_SINCE_I_REALLY_KNOW_WHAT_IM_DOING",
     )
@@ -0,0 +1,36 @@
+// generate group varint lookup tables
+let vec = "";
+let lens = "";
+
+for (let control = 0; control < 256; control++) {
+    let shuf = "";
+    let offset = 0;
+    let descr = [];
+    for (let i = 0; i < 4; i++) {
+        let len = ((control >> (i * 2)) & 0b11) + 1;
+        for (let j = 0; j < 4; j++) {
+            shuf += (j < len ? "" + (offset + j) : "255") + ", ";
+        }
+        offset += len;
+        descr.push(len);
+    }
+
+    vec += `        ${shuf.trim()} // ${descr.join(", ")}\n`;
+    lens += `    ${offset}, // 0b${control.toString(2).padStart(8, "0")}\n`;
+}
+
+console.log(`#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(align(16), C)]
+struct Align128<T>(T);
+
+pub static LOOKUP_GROUP_VEC: [__m128i; 256] = unsafe {
+    core::mem::transmute(Align128::<[u8; 4096]>([
+${vec}    ]))
+};
+
+pub static LOOKUP_GROUP_LEN: [u8; 256] = [
+${lens}];`);
@@ -0,0 +1,87 @@
+// generate masked vbyte lookup tables
+let vec = "";
+for (let idx = 0; idx < 64; idx++) {
+    let shuf = "";
+    let offset = 0;
+    let descr = [];
+    for (let i = 0; i < 8; i++) {
+        let len = i < 6 ? ((idx >> i) & 1) + 1 : 0;
+        for (let j = 0; j < 2; j++) {
+            shuf += (j < len ? "" + (offset + j) : "255") + ", ";
+        }
+        offset += len;
+        if (i < 6) {
+            descr.push(len);
+        }
+    }
+
+    vec += `        ${shuf.trim()} // ${descr.join(", ")}\n`;
+}
+
+let table = "";
+for (let mask = 0; mask < 2**12; mask++) {
+    // find the lengths of all varints that end within the 12-byte window
+    let lens = [];
+    let start = 0;
+    for (let i = 0; i < 12; i++) {
+        if (((mask >> i) & 1) === 0) {
+            lens.push(i - start + 1);
+            start = i + 1;
+        }
+    }
+
+    let sum = (n) => lens.slice(0, n).reduce((a, b) => a + b, 0);
+    let fits = (n, max) => lens.length >= n && lens.slice(0, n).every((len) => len <= max);
+
+    // kind 0: six values of up to 2 bytes in 16-bit lanes
+    // kind 1: four values of up to 3 bytes in 32-bit lanes (LOOKUP_QUAD_VEC)
+    // kind 2: two values of up to 5 bytes in 64-bit lanes (LOOKUP_DOUBLE_VEC)
+    // kind 3: the first varint is too long for a u32
+    let kind, idx, count, consumed;
+    if (fits(6, 2)) {
+        kind = 0;
+        idx = lens.slice(0, 6).reduce((a, len, i) => a | ((len - 1) << i), 0);
+        count = 6;
+        consumed = sum(6);
+    } else if (fits(4, 3)) {
+        kind = 1;
+        idx = (lens[0] - 1) * 27 + (lens[1] - 1) * 9 + (lens[2] - 1) * 3 + (lens[3] - 1);
+        count = 4;
+        consumed = sum(4);
+    } else if (fits(2, 5)) {
+        kind = 2;
+        idx = (lens[0] - 1) * 10 + (lens[1] - 1);
+        count = 2;
+        consumed = sum(2);
+    } else if (fits(1, 5)) {
+        // the second value will be decoded again in the next step
+        kind = 2;
+        idx = (lens[0] - 1) * 10;
+        count = 1;
+        consumed = sum(1);
+    } else {
+        kind = 3;
+        idx = 0;
+        count = 0;
+        consumed = 0;
+    }
+
+    let packed = idx | consumed << 8 | count << 12 | kind << 16;
+    table += `    0x${packed.toString(16).padStart(8, "0")}, // 0b${mask.toString(2).padStart(12, "0")}\n`;
+}
+
+console.log(`#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(align(16), C)]
+struct Align128<T>(T);
+
+pub static LOOKUP_MASKED_VEC16: [__m128i; 64] = unsafe {
+    core::mem::transmute(Align128::<[u8; 1024]>([
+${vec}    ]))
+};
+
+pub static LOOKUP_MASKED_STEP1: [u32; 4096] = [
+${table}];`);
@@ -0,0 +1,135 @@
+//! Decoding into and encoding into fixed-capacity [`ArrayVec`]s, without allocating.
+//!
+//! Instead of panicking when a vector is full, these functions return
+//! [`VarIntDecodeError::CapacityExceeded`] when decoding, or [`CapacityError`] when encoding.
+//!
+//! Requires the `arrayvec` feature, and works without the `std` feature.
+
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+use arrayvec::{ArrayVec, CapacityError};
+
+/// Decodes adjacent varints until the end of the input slice, appending them to `out`. Returns
+/// the number of values decoded.
+///
+/// If `out` runs out of space, [`VarIntDecodeError::CapacityExceeded`] is returned. The values
+/// decoded before any error are left in `out`.
+///
+/// # Examples
+/// ```
+/// use arrayvec::ArrayVec;
+/// use varint_simd::{arrayvec::decode_into, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = ArrayVec::<u32, 4>::new();
+///     assert_eq!(decode_into(&[0x01, 0xB9, 0x0A], &mut out)?, 2);
+///     assert_eq!(out.as_slice(), [1, 1337]);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_into<T: VarIntTarget, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+) -> Result<usize, VarIntDecodeError> {
+    crate::decode::decode_packed::<T>(bytes, |num| out.try_push(num).is_ok())
+}
+
+/// Decodes adjacent varints in ZigZag format until the end of the input slice, appending them to
+/// `out`. Returns the number of values decoded.
+///
+/// See also: [`decode_into`]
+#[inline]
+pub fn decode_zigzag_into<T: SignedVarIntTarget, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+) -> Result<usize, VarIntDecodeError> {
+    crate::decode::decode_packed::<T::Unsigned>(bytes, |num| out.try_push(num.unzigzag()).is_ok())
+}
+
+/// Encodes a single number to a varint, and appends it to `out`. Returns the number of bytes
+/// written. Requires SSE2 support.
+///
+/// If `out` does not have enough space for the whole varint, nothing is written.
+///
+/// # Examples
+/// ```
+/// use arrayvec::ArrayVec;
+/// use varint_simd::arrayvec::encode_into;
+///
+/// let mut out = ArrayVec::<u8, 3>::new();
+/// assert_eq!(encode_into(1337u32, &mut out), Ok(2));
+/// assert_eq!(out.as_slice(), [185, 10]);
+/// assert!(encode_into(1337u32, &mut out).is_err());
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_into<T: VarIntTarget, const CAP: usize>(
+    num: T,
+    out: &mut ArrayVec<u8, CAP>,
+) -> Result<u8, CapacityError> {
+    let (data, len) = crate::encode(num);
+    out.try_extend_from_slice(&data[..len as usize])?;
+
+    Ok(len)
+}
+
+/// Encodes a single number to a varint in ZigZag format, and appends it to `out`. Returns the
+/// number of bytes written.
+///
+/// See also: [`encode_into`]
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_zigzag_into<T: SignedVarIntTarget, const CAP: usize>(
+    num: T,
+    out: &mut ArrayVec<u8, CAP>,
+) -> Result<u8, CapacityError> {
+    encode_into(T::Unsigned::zigzag(num), out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let values = [0i32, -1, 1, 300, -300, i32::MIN, i32::MAX];
+
+        let mut encoded = ArrayVec::<u8, 64>::new();
+        for num in values {
+            encode_zigzag_into(num, &mut encoded).unwrap();
+        }
+
+        let mut decoded = ArrayVec::<i32, 7>::new();
+        assert_eq!(decode_zigzag_into(&encoded, &mut decoded).unwrap(), 7);
+        assert_eq!(decoded.as_slice(), values);
+    }
+
+    #[test]
+    fn capacity() {
+        let mut encoded = ArrayVec::<u8, 6>::new();
+        assert_eq!(encode_into(u32::MAX, &mut encoded), Ok(5));
+        assert!(encode_into(300u16, &mut encoded).is_err());
+        assert_eq!(encoded.len(), 5);
+        assert_eq!(encode_into(1u16, &mut encoded), Ok(1));
+
+        let mut decoded = ArrayVec::<u32, 1>::new();
+        assert!(matches!(
+            decode_into(&encoded, &mut decoded),
+            Err(VarIntDecodeError::CapacityExceeded)
+        ));
+        assert_eq!(decoded.as_slice(), [u32::MAX]);
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut decoded = ArrayVec::<u32, 4>::new();
+        assert!(matches!(
+            decode_into(&[0x01, 0x80], &mut decoded),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
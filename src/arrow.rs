@@ -0,0 +1,184 @@
+//! Conversions between packed varints and [Apache Arrow](https://arrow.apache.org) arrays.
+//!
+//! A packed field is a sequence of adjacent varints with no other framing, as in protobuf packed
+//! repeated fields. The decoders write the values directly into an Arrow buffer, which becomes
+//! the values buffer of the resulting array without copying.
+//!
+//! Requires the `arrow` feature.
+//!
+//! # Examples
+//! ```
+//! use arrow_array::{Array, Int64Array};
+//! use varint_simd::arrow::{decode_zigzag_i64_array, encode_zigzag_i64_array};
+//!
+//! let array = decode_zigzag_i64_array(&[0x00, 0x01, 0xAC, 0x02]).unwrap();
+//! assert_eq!(array, Int64Array::from(vec![0, -1, 150]));
+//!
+//! let mut encoded = Vec::new();
+//! encode_zigzag_i64_array(&array, &mut encoded);
+//! assert_eq!(encoded, [0x00, 0x01, 0xAC, 0x02]);
+//! ```
+
+use crate::num::VarIntTarget;
+use crate::VarIntDecodeError;
+
+use arrow_array::types::{Int64Type, UInt64Type};
+use arrow_array::{Array, Int64Array, PrimitiveArray, UInt64Array};
+use arrow_buffer::{ArrowNativeType, BufferBuilder, ScalarBuffer};
+
+/// Decodes a packed sequence of varints into a `UInt64Array`. The whole slice must consist of
+/// complete varints.
+///
+/// # Examples
+/// ```
+/// use arrow_array::UInt64Array;
+/// use varint_simd::{arrow::decode_u64_array, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let array = decode_u64_array(&[0x01, 0xB9, 0x0A])?;
+///     assert_eq!(array, UInt64Array::from(vec![1, 1337]));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_u64_array(bytes: &[u8]) -> Result<UInt64Array, VarIntDecodeError> {
+    let values = decode_values::<u64, u64>(bytes, |num| num)?;
+    Ok(PrimitiveArray::<UInt64Type>::new(values, None))
+}
+
+/// Decodes a packed sequence of varints in ZigZag format into an `Int64Array`.
+///
+/// See also: [`decode_u64_array`]
+#[inline]
+pub fn decode_zigzag_i64_array(bytes: &[u8]) -> Result<Int64Array, VarIntDecodeError> {
+    let values = decode_values::<u64, i64>(bytes, |num| num.unzigzag())?;
+    Ok(PrimitiveArray::<Int64Type>::new(values, None))
+}
+
+/// Encodes the values of a `UInt64Array` as packed varints, appending them to `out`.
+///
+/// **Panics:** if the array contains nulls, which cannot be represented.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_u64_array(array: &UInt64Array, out: &mut Vec<u8>) {
+    assert_eq!(array.null_count(), 0, "array contains nulls");
+    out.reserve(array.len());
+
+    for num in array.values() {
+        let (data, len) = crate::encode(*num);
+        out.extend_from_slice(&data[..len as usize]);
+    }
+}
+
+/// Encodes the values of an `Int64Array` as packed varints in ZigZag format, appending them to
+/// `out`.
+///
+/// **Panics:** if the array contains nulls, which cannot be represented.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_zigzag_i64_array(array: &Int64Array, out: &mut Vec<u8>) {
+    assert_eq!(array.null_count(), 0, "array contains nulls");
+    out.reserve(array.len());
+
+    for num in array.values() {
+        let (data, len) = crate::encode_zigzag(*num);
+        out.extend_from_slice(&data[..len as usize]);
+    }
+}
+
+#[inline(always)]
+fn decode_values<T: VarIntTarget, U: ArrowNativeType>(
+    bytes: &[u8],
+    convert: impl Fn(T) -> U,
+) -> Result<ScalarBuffer<U>, VarIntDecodeError> {
+    // Every varint ends with exactly one byte without a continuation bit
+    let count = bytes.iter().filter(|b| **b < 0x80).count();
+    let mut builder = BufferBuilder::<U>::new(count);
+
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let (num, len) = crate::decode::<T>(&bytes[pos..])?;
+        pos += len;
+        if pos > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        builder.append(convert(num));
+    }
+
+    Ok(ScalarBuffer::from(builder.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in [0, 1, 2, 15, 16, 17, 1000] {
+            let values: Vec<u64> = (0..count)
+                .map(|_| rng.gen::<u64>() >> rng.gen_range(0..64))
+                .collect();
+
+            let mut expected = vec![];
+            let mut expected_zigzag = vec![];
+            for num in &values {
+                let (data, len) = crate::encode(*num);
+                expected.extend_from_slice(&data[..len as usize]);
+                let (data, len) = crate::encode_zigzag(*num as i64);
+                expected_zigzag.extend_from_slice(&data[..len as usize]);
+            }
+
+            let array = decode_u64_array(&expected).unwrap();
+            assert_eq!(array.values().as_ref(), &values[..]);
+            let mut encoded = vec![];
+            encode_u64_array(&array, &mut encoded);
+            assert_eq!(encoded, expected);
+
+            let array = decode_zigzag_i64_array(&expected_zigzag).unwrap();
+            assert!(array
+                .values()
+                .iter()
+                .zip(&values)
+                .all(|(a, b)| *a == *b as i64));
+            let mut encoded = vec![];
+            encode_zigzag_i64_array(&array, &mut encoded);
+            assert_eq!(encoded, expected_zigzag);
+        }
+    }
+
+    #[test]
+    fn sliced() {
+        let array = UInt64Array::from(vec![1, 300, 2]).slice(1, 2);
+        let mut encoded = vec![];
+        encode_u64_array(&array, &mut encoded);
+        assert_eq!(encoded, [0xAC, 0x02, 0x02]);
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(
+            decode_u64_array(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_u64_array(&[0x01, 0x80]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_zigzag_i64_array(&[0xFF; 5]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
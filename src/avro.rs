@@ -0,0 +1,391 @@
+//! Apache Avro `int` and `long` values, and array and map blocks.
+//!
+//! Avro stores `int` and `long` values as ZigZag varints of at most 5 and 10 bytes respectively.
+//! Arrays and maps are written as a series of blocks, each starting with a `long` item count and
+//! ending with a block of zero items. A negative count means that the number of items is its
+//! absolute value, and that it is followed by a `long` containing the size of the block in bytes.
+//!
+//! See: <https://avro.apache.org/docs/1.11.1/specification/#binary-encoding>
+
+use crate::VarIntDecodeError;
+
+/// Encodes an Avro `int`.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// integer.
+///
+/// # Examples
+/// ```
+/// use varint_simd::avro::encode_int;
+///
+/// let (data, len) = encode_int(-64);
+/// assert_eq!(&data[..len as usize], &[0x7F]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_int(num: i32) -> ([u8; 16], u8) {
+    crate::encode_zigzag(num)
+}
+
+/// Encodes an Avro `long`.
+///
+/// See also: [`encode_int`]
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_long(num: i64) -> ([u8; 16], u8) {
+    crate::encode_zigzag(num)
+}
+
+/// Decodes an Avro `int` from the input slice. Values longer than 5 bytes are rejected with
+/// [`VarIntDecodeError::Overflow`].
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{avro::decode_int, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(decode_int(&[0x80, 0x01])?, (64, 2));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_int(bytes: &[u8]) -> Result<(i32, usize), VarIntDecodeError> {
+    let (num, len) = crate::decode_zigzag::<i32>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num, len))
+    }
+}
+
+/// Decodes an Avro `long` from the input slice. Values longer than 10 bytes are rejected with
+/// [`VarIntDecodeError::Overflow`].
+///
+/// See also: [`decode_int`]
+#[inline]
+pub fn decode_long(bytes: &[u8]) -> Result<(i64, usize), VarIntDecodeError> {
+    let (num, len) = crate::decode_zigzag::<i64>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num, len))
+    }
+}
+
+/// The header of an array or map block
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BlockHeader {
+    /// The number of items in the block. Zero marks the end of the array or map.
+    pub count: u64,
+    /// The size of the block in bytes, if it was written
+    pub byte_size: Option<u64>,
+}
+
+/// Encodes the header of an array or map block, and writes the resulting data to the slice. If
+/// `byte_size` is given, the count is written as a negative number followed by the size. Returns
+/// the number of bytes written (maximum 20 bytes).
+///
+/// **Panics:** if `count` or `byte_size` exceed `i64::MAX`, or if the slice is too small to
+/// contain the header.
+///
+/// # Examples
+/// ```
+/// use varint_simd::avro::{encode_block_header_to_slice, BlockHeader};
+///
+/// let mut data = [0u8; 20];
+/// let len = encode_block_header_to_slice(BlockHeader { count: 3, byte_size: Some(10) }, &mut data);
+/// assert_eq!(&data[..len as usize], &[0x05, 0x14]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_block_header_to_slice(header: BlockHeader, slice: &mut [u8]) -> u8 {
+    let count = i64::try_from(header.count).expect("block count exceeds i64::MAX");
+
+    match header.byte_size {
+        Some(byte_size) => {
+            let byte_size = i64::try_from(byte_size).expect("block size exceeds i64::MAX");
+
+            let (data, count_len) = encode_long(-count);
+            slice[..count_len as usize].copy_from_slice(&data[..count_len as usize]);
+            let (data, size_len) = encode_long(byte_size);
+            slice[count_len as usize..(count_len + size_len) as usize]
+                .copy_from_slice(&data[..size_len as usize]);
+
+            count_len + size_len
+        }
+        None => {
+            let (data, len) = encode_long(count);
+            slice[..len as usize].copy_from_slice(&data[..len as usize]);
+            len
+        }
+    }
+}
+
+/// Decodes the header of an array or map block from the input slice.
+///
+/// Produces a tuple containing the decoded header and the number of bytes read. A negative block
+/// size is rejected with [`VarIntDecodeError::Invalid`].
+///
+/// # Examples
+/// ```
+/// use varint_simd::{avro::{decode_block_header, BlockHeader}, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let (header, len) = decode_block_header(&[0x05, 0x14])?;
+///     assert_eq!(header, BlockHeader { count: 3, byte_size: Some(10) });
+///     assert_eq!(len, 2);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_block_header(bytes: &[u8]) -> Result<(BlockHeader, usize), VarIntDecodeError> {
+    let (count, len) = decode_long(bytes)?;
+
+    if count >= 0 {
+        return Ok((
+            BlockHeader {
+                count: count as u64,
+                byte_size: None,
+            },
+            len,
+        ));
+    }
+
+    let (byte_size, size_len) = decode_long(&bytes[len..])?;
+    if byte_size < 0 {
+        return Err(VarIntDecodeError::Invalid);
+    }
+
+    Ok((
+        BlockHeader {
+            count: count.unsigned_abs(),
+            byte_size: Some(byte_size as u64),
+        },
+        len + size_len,
+    ))
+}
+
+/// Decodes an entire `array<long>` from the input slice, appending the items to `out`. Returns
+/// the number of bytes read, including the final empty block.
+///
+/// If a block has a byte size, it must match the number of bytes taken up by its items, otherwise
+/// [`VarIntDecodeError::Invalid`] is returned.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{avro::decode_long_array, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = Vec::new();
+///     // a block of 2 items, a block of 1 item with a byte size, and the end of the array
+///     let len = decode_long_array(&[0x04, 0x01, 0x02, 0x01, 0x02, 0x03, 0x00], &mut out)?;
+///     assert_eq!(out, [-1, 1, -2]);
+///     assert_eq!(len, 7);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "std")))]
+pub fn decode_long_array(bytes: &[u8], out: &mut Vec<i64>) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+
+    loop {
+        let (header, len) = decode_block_header(&bytes[pos..])?;
+        pos += len;
+
+        if header.count == 0 {
+            return Ok(pos);
+        }
+
+        // Every item takes at least one byte, so this also bounds the allocation below
+        let remaining = bytes.len() - pos;
+        if header.count > remaining as u64 {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+        let count = header.count as usize;
+        out.reserve(count);
+
+        let start = pos;
+        for _ in 0..count {
+            let (num, len) = decode_long(&bytes[pos..])?;
+            out.push(num);
+            pos += len;
+        }
+
+        if let Some(byte_size) = header.byte_size {
+            if (pos - start) as u64 != byte_size {
+                return Err(VarIntDecodeError::Invalid);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_and_longs() {
+        // examples from the Avro specification
+        for (num, encoded) in [
+            (0, &[0x00][..]),
+            (-1, &[0x01]),
+            (1, &[0x02]),
+            (-2, &[0x03]),
+            (2, &[0x04]),
+            (-64, &[0x7F]),
+            (64, &[0x80, 0x01]),
+        ] {
+            let (data, len) = encode_int(num);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(decode_int(encoded).unwrap(), (num, encoded.len()));
+
+            let (data, len) = encode_long(num as i64);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(decode_long(encoded).unwrap(), (num as i64, encoded.len()));
+        }
+
+        for num in [i32::MIN, i32::MAX] {
+            let (data, len) = encode_int(num);
+            assert_eq!(len, 5);
+            assert_eq!(decode_int(&data[..5]).unwrap(), (num, 5));
+        }
+        for num in [i64::MIN, i64::MAX] {
+            let (data, len) = encode_long(num);
+            assert_eq!(len, 10);
+            assert_eq!(decode_long(&data[..10]).unwrap(), (num, 10));
+        }
+    }
+
+    #[test]
+    fn length_limits() {
+        // 6 bytes
+        assert!(matches!(
+            decode_int(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        // 11 bytes
+        assert!(matches!(
+            decode_long(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_long(&[0x80, 0x80]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn block_headers() {
+        let mut data = [0u8; 20];
+        for header in [
+            BlockHeader {
+                count: 0,
+                byte_size: None,
+            },
+            BlockHeader {
+                count: 1000,
+                byte_size: None,
+            },
+            BlockHeader {
+                count: 1000,
+                byte_size: Some(2000),
+            },
+            BlockHeader {
+                count: i64::MAX as u64,
+                byte_size: Some(i64::MAX as u64),
+            },
+        ] {
+            let len = encode_block_header_to_slice(header, &mut data);
+            assert_eq!(
+                decode_block_header(&data[..len as usize]).unwrap(),
+                (header, len as usize)
+            );
+        }
+
+        // negative byte size
+        assert!(matches!(
+            decode_block_header(&[0x01, 0x01]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        assert!(matches!(
+            decode_block_header(&[0x01]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    fn encode_array(blocks: &[&[i64]], with_size: bool) -> Vec<u8> {
+        let mut encoded = vec![];
+        for block in blocks {
+            let mut items = vec![];
+            for num in *block {
+                let (data, len) = encode_long(*num);
+                items.extend_from_slice(&data[..len as usize]);
+            }
+
+            let mut header = [0u8; 20];
+            let len = encode_block_header_to_slice(
+                BlockHeader {
+                    count: block.len() as u64,
+                    byte_size: if with_size {
+                        Some(items.len() as u64)
+                    } else {
+                        None
+                    },
+                },
+                &mut header,
+            );
+            encoded.extend_from_slice(&header[..len as usize]);
+            encoded.extend_from_slice(&items);
+        }
+        encoded.push(0);
+        encoded
+    }
+
+    #[test]
+    fn long_arrays() {
+        let block1: Vec<i64> = (0..100).map(|i| i * i * i - 5000).collect();
+        let block2 = [i64::MIN, i64::MAX, 0];
+
+        for with_size in [false, true] {
+            let encoded = encode_array(&[&block1, &block2], with_size);
+            let mut out = vec![42];
+            assert_eq!(
+                decode_long_array(&encoded, &mut out).unwrap(),
+                encoded.len()
+            );
+            assert_eq!(out[0], 42);
+            assert_eq!(out[1..101], block1[..]);
+            assert_eq!(out[101..], block2[..]);
+
+            assert!(matches!(
+                decode_long_array(&encoded[..encoded.len() - 1], &mut vec![]),
+                Err(VarIntDecodeError::NotEnoughBytes)
+            ));
+        }
+
+        let mut out = vec![];
+        assert_eq!(decode_long_array(&[0], &mut out).unwrap(), 1);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn long_array_errors() {
+        // wrong byte size
+        assert!(matches!(
+            decode_long_array(&[0x03, 0x04, 0x80, 0x01, 0x00], &mut vec![]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        // count larger than the input
+        assert!(matches!(
+            decode_long_array(&[0xFE, 0xFF, 0xFF, 0xFF, 0x0F, 0x00], &mut vec![]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+//! Bitcoin CompactSize integers.
+//!
+//! Values below `0xFD` are stored in a single byte. Larger values are stored as a marker byte
+//! followed by a little-endian integer: `0xFD` for a `u16`, `0xFE` for a `u32` and `0xFF` for a
+//! `u64`. As in Bitcoin Core, decoding rejects values that are not stored in the shortest
+//! possible form with [`VarIntDecodeError::Invalid`].
+
+use crate::VarIntDecodeError;
+
+/// Encodes a single number to a CompactSize integer.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// integer.
+///
+/// # Examples
+/// ```
+/// use varint_simd::compact_size::encode;
+///
+/// let encoded = encode(1337);
+/// assert_eq!(encoded, ([0xFD, 57, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 3));
+/// ```
+#[inline]
+pub fn encode(num: u64) -> ([u8; 16], u8) {
+    let mut res = [0u8; 16];
+
+    if num < 0xFD {
+        res[0] = num as u8;
+        return (res, 1);
+    }
+
+    let len = if num <= u16::MAX as u64 {
+        res[0] = 0xFD;
+        3
+    } else if num <= u32::MAX as u64 {
+        res[0] = 0xFE;
+        5
+    } else {
+        res[0] = 0xFF;
+        9
+    };
+
+    res[1..9].copy_from_slice(&num.to_le_bytes());
+    res[len..9].fill(0);
+
+    (res, len as u8)
+}
+
+/// Encodes a single number to a CompactSize integer, and writes the resulting data to the slice.
+/// Returns the number of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode`]
+///
+/// **Panics:** if the slice is too small to contain the integer.
+#[inline]
+pub fn encode_to_slice(num: u64, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Decodes a single CompactSize integer from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. For best
+/// performance, provide a slice at least 16 bytes in length, or use the unsafe version directly.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{compact_size::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode(&[0xFD, 57, 5])?;
+///     assert_eq!(decoded, (1337, 3));
+///
+///     // Not the shortest form
+///     assert!(decode(&[0xFD, 1, 0]).is_err());
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode(bytes: &[u8]) -> Result<(u64, usize), VarIntDecodeError> {
+    let (num, len) = if bytes.len() >= 16 {
+        unsafe { decode_unsafe(bytes.as_ptr()) }
+    } else if !bytes.is_empty() {
+        let mut data = [0u8; 16];
+        data[..bytes.len()].copy_from_slice(bytes);
+        unsafe { decode_unsafe(data.as_ptr()) }
+    } else {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    };
+
+    if len > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    let min = match len {
+        1 => 0,
+        3 => 0xFD,
+        5 => 0x10000,
+        _ => 0x100000000,
+    };
+    if num < min {
+        Err(VarIntDecodeError::Invalid)
+    } else {
+        Ok((num, len))
+    }
+}
+
+/// Decodes only the length of a single CompactSize integer from the input slice.
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+#[inline]
+pub fn decode_len(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(first) => Ok(unsafe { decode_len_unsafe(first) }),
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes the length of the next CompactSize integer
+///
+/// # Safety
+/// The pointer must point to at least one byte of allocated memory.
+#[inline]
+pub unsafe fn decode_len_unsafe(bytes: *const u8) -> usize {
+    match *bytes {
+        0xFD => 3,
+        0xFE => 5,
+        0xFF => 9,
+        _ => 1,
+    }
+}
+
+/// Decodes a single CompactSize integer from the input pointer. Returns a tuple containing the
+/// decoded number and the number of bytes read.
+///
+/// # Safety
+/// There must be at least 16 bytes of allocated memory after the beginning of the pointer.
+/// Otherwise, there may be undefined behavior. Any data after the end of the integer are ignored.
+/// Values that are not stored in the shortest possible form are not rejected.
+#[inline]
+pub unsafe fn decode_unsafe(bytes: *const u8) -> (u64, usize) {
+    let len = decode_len_unsafe(bytes);
+    if len == 1 {
+        return (*bytes as u64, 1);
+    }
+
+    let data = u64::from_le(bytes.add(1).cast::<u64>().read_unaligned());
+    // len is 3, 5, or 9
+    let unused = 64 - (len as u32 - 1) * 8;
+
+    ((data << unused) >> unused, len)
+}
+
+/// Skips over `count` adjacent records, each consisting of `header` fixed bytes, a CompactSize
+/// length, that many bytes of data, and `trailer` fixed bytes. Returns the total number of bytes
+/// in the records.
+///
+/// For example, transaction inputs are records with a 36 byte header (the previous output) and a
+/// 4 byte trailer (the sequence number), and transaction outputs are records with an 8 byte
+/// header (the amount) and no trailer.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{compact_size::skip_records, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     // two outputs, with scripts of 2 and 1 bytes
+///     let outputs = [
+///         1, 0, 0, 0, 0, 0, 0, 0, 2, 0x51, 0x51,
+///         2, 0, 0, 0, 0, 0, 0, 0, 1, 0x51,
+///     ];
+///     assert_eq!(skip_records(&outputs, 2, 8, 0)?, outputs.len());
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn skip_records(
+    bytes: &[u8],
+    count: usize,
+    header: usize,
+    trailer: usize,
+) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0usize;
+
+    for _ in 0..count {
+        pos = pos
+            .checked_add(header)
+            .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        let rest = bytes.get(pos..).ok_or(VarIntDecodeError::NotEnoughBytes)?;
+
+        // Almost every record has a single-byte length, so check for that first
+        let (data_len, len) = match rest.first() {
+            Some(first) if *first < 0xFD => (*first as u64, 1),
+            _ => decode(rest)?,
+        };
+
+        pos = usize::try_from(data_len)
+            .ok()
+            .and_then(|data_len| (pos + len).checked_add(data_len))
+            .and_then(|pos| pos.checked_add(trailer))
+            .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        if pos > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+    }
+
+    Ok(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_len, encode, encode_to_slice, skip_records};
+    use crate::VarIntDecodeError;
+
+    fn check(value: u64, encoded: &[u8]) {
+        let mut expected = [0u8; 16];
+        expected[..encoded.len()].copy_from_slice(encoded);
+
+        let a = encode(value);
+        assert_eq!(a.0, expected);
+        assert_eq!(a.1 as usize, encoded.len());
+
+        assert_eq!(decode(encoded).unwrap(), (value, encoded.len()));
+        assert_eq!(decode(&expected).unwrap(), (value, encoded.len()));
+        assert_eq!(decode_len(&expected).unwrap(), encoded.len());
+    }
+
+    #[test]
+    fn roundtrip() {
+        check(0, &[0x00]);
+        check(0xFC, &[0xFC]);
+        check(0xFD, &[0xFD, 0xFD, 0x00]);
+        check(0xFFFF, &[0xFD, 0xFF, 0xFF]);
+        check(0x10000, &[0xFE, 0x00, 0x00, 0x01, 0x00]);
+        check(0xFFFFFFFF, &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF]);
+        check(0x100000000, &[0xFF, 0, 0, 0, 0, 1, 0, 0, 0]);
+        check(u64::MAX, &[0xFF; 9]);
+    }
+
+    #[test]
+    fn non_canonical() {
+        for encoded in [
+            &[0xFD, 0xFC, 0x00][..],
+            &[0xFE, 0xFF, 0xFF, 0x00, 0x00],
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0],
+        ] {
+            assert!(matches!(decode(encoded), Err(VarIntDecodeError::Invalid)));
+        }
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0xFE, 0, 0, 1]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn skip() {
+        // inputs with scripts of 0, 300, and 5 bytes
+        let mut inputs = vec![];
+        for script_len in [0, 300, 5] {
+            inputs.extend_from_slice(&[0xAB; 36]);
+            let mut len = [0u8; 9];
+            let size = encode_to_slice(script_len as u64, &mut len);
+            inputs.extend_from_slice(&len[..size as usize]);
+            inputs.extend(std::iter::repeat_n(0x51, script_len));
+            inputs.extend_from_slice(&[0xFF; 4]);
+        }
+
+        assert_eq!(skip_records(&inputs, 3, 36, 4).unwrap(), inputs.len());
+        assert_eq!(skip_records(&inputs, 1, 36, 4).unwrap(), 41);
+        assert!(matches!(
+            skip_records(&inputs[..inputs.len() - 1], 3, 36, 4),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            skip_records(&inputs, 4, 36, 4),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn skip_huge_length() {
+        // a data length that would wrap around when the next header is added
+        let mut records = vec![0u8; 20];
+        records.push(0xFF);
+        records.extend_from_slice(&(u64::MAX - 40).to_le_bytes());
+        records.extend_from_slice(&[0u8; 40]);
+
+        assert!(matches!(
+            skip_records(&records, 2, 20, 0),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            skip_records(&records, usize::MAX, 20, 0),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+//! The varint encoding of `bincode`, as used with `VarintEncoding` in bincode 1 and by default in
+//! bincode 2.
+//!
+//! Values below 251 are stored in a single byte. Larger values are stored as a marker byte
+//! followed by a little-endian integer: 251 for a `u16`, 252 for a `u32`, 253 for a `u64` and 254
+//! for a `u128`. Signed integers are ZigZag encoded first.
+
+use super::is_raw;
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+/// Encodes a single number to a bincode varint.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint.
+///
+/// # Examples
+/// ```
+/// use varint_simd::compat::bincode::encode;
+///
+/// let encoded = encode::<u32>(1337);
+/// assert_eq!(encoded, ([251, 57, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 3));
+/// ```
+#[inline]
+pub fn encode<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
+    let num = num.to_u64();
+    let mut res = [0u8; 16];
+
+    if num < 251 || is_raw::<T>() {
+        res[0] = num as u8;
+        return (res, 1);
+    }
+
+    let len = if num <= u16::MAX as u64 {
+        res[0] = 251;
+        3
+    } else if num <= u32::MAX as u64 {
+        res[0] = 252;
+        5
+    } else {
+        res[0] = 253;
+        9
+    };
+
+    res[1..9].copy_from_slice(&num.to_le_bytes());
+    res[len..9].fill(0);
+
+    (res, len as u8)
+}
+
+/// Convenience function for encoding a single signed integer in ZigZag format to a bincode varint.
+/// See also: [`encode`]
+#[inline]
+pub fn encode_zigzag<T: SignedVarIntTarget>(num: T) -> ([u8; 16], u8) {
+    if is_raw::<T>() {
+        let mut res = [0u8; 16];
+        res[0] = num.to_i64() as u8;
+        (res, 1)
+    } else {
+        encode(T::Unsigned::zigzag(num))
+    }
+}
+
+/// Encodes a `u128` to a bincode varint.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint (maximum 17 bytes).
+#[inline]
+pub fn encode_u128(num: u128) -> ([u8; 17], u8) {
+    let mut res = [0u8; 17];
+
+    if let Ok(num) = u64::try_from(num) {
+        let (data, len) = encode(num);
+        res[..16].copy_from_slice(&data);
+        (res, len)
+    } else {
+        res[0] = 254;
+        res[1..].copy_from_slice(&num.to_le_bytes());
+        (res, 17)
+    }
+}
+
+/// Encodes an `i128` in ZigZag format to a bincode varint.
+///
+/// See also: [`encode_u128`]
+#[inline]
+pub fn encode_i128(num: i128) -> ([u8; 17], u8) {
+    encode_u128(((num << 1) ^ (num >> 127)) as u128)
+}
+
+/// Decodes only the length of a single bincode varint from the input slice. The marker byte 255
+/// is rejected with [`VarIntDecodeError::Invalid`].
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+#[inline]
+pub fn decode_len(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(0..=250) => Ok(1),
+        Some(251) => Ok(3),
+        Some(252) => Ok(5),
+        Some(253) => Ok(9),
+        Some(254) => Ok(17),
+        Some(_) => Err(VarIntDecodeError::Invalid),
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes a single bincode varint from the input slice as a `u128`.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+#[inline]
+pub fn decode_u128(bytes: &[u8]) -> Result<(u128, usize), VarIntDecodeError> {
+    let len = decode_len(bytes)?;
+    if len == 1 {
+        return Ok((bytes[0] as u128, 1));
+    }
+
+    let data = bytes.get(1..len).ok_or(VarIntDecodeError::NotEnoughBytes)?;
+    let mut num = [0u8; 16];
+    num[..len - 1].copy_from_slice(data);
+
+    Ok((u128::from_le_bytes(num), len))
+}
+
+/// Decodes a single bincode varint from the input slice as an `i128` in ZigZag format.
+///
+/// See also: [`decode_u128`]
+#[inline]
+pub fn decode_i128(bytes: &[u8]) -> Result<(i128, usize), VarIntDecodeError> {
+    decode_u128(bytes).map(|(num, len)| (((num >> 1) as i128) ^ -((num & 1) as i128), len))
+}
+
+/// Decodes a single bincode varint from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. As in bincode 1,
+/// a marker for a wider integer is accepted as long as the value fits in the target type. bincode 2
+/// rejects such input with an invalid integer type error.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{compat::bincode::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode::<u32>(&[251, 57, 5])?;
+///     assert_eq!(decoded, (1337, 3));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode<T: VarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    if is_raw::<T>() {
+        let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        return Ok((T::cast_u32(first as u32), 1));
+    }
+
+    let (num, len) = decode_u128(bytes)?;
+    let num = u64::try_from(num).map_err(|_| VarIntDecodeError::Overflow)?;
+
+    let result = T::cast_u64(num);
+    if result.to_u64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+/// Convenience function for decoding a single bincode varint in ZigZag format from the input
+/// slice. See also: [`decode`]
+#[inline]
+pub fn decode_zigzag<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    if is_raw::<T>() {
+        let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        return Ok((T::cast_i64(first as i8 as i64), 1));
+    }
+
+    decode::<T::Unsigned>(bytes).map(|r| (r.0.unzigzag(), r.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<T: VarIntTarget>(value: T, encoded: &[u8]) {
+        let mut expected = [0u8; 16];
+        expected[..encoded.len()].copy_from_slice(encoded);
+
+        let a = encode(value);
+        assert_eq!(a.0, expected);
+        assert_eq!(a.1 as usize, encoded.len());
+
+        let roundtrip: (T, usize) = decode(encoded).unwrap();
+        assert_eq!(roundtrip, (value, encoded.len()));
+        assert_eq!(decode_len(encoded).unwrap(), encoded.len());
+
+        let (data, len) = encode_u128(value.to_u64() as u128);
+        assert_eq!(&data[..len as usize], encoded);
+    }
+
+    #[test]
+    fn roundtrip() {
+        check(0u16, &[0]);
+        check(250u16, &[250]);
+        check(251u16, &[251, 251, 0]);
+        check(u8::MAX as u16, &[251, 255, 0]);
+        check(u16::MAX, &[251, 255, 255]);
+        check(u16::MAX as u32 + 1, &[252, 0, 0, 1, 0]);
+        check(u32::MAX, &[252, 255, 255, 255, 255]);
+        check(u32::MAX as u64 + 1, &[253, 0, 0, 0, 0, 1, 0, 0, 0]);
+        check(u64::MAX, &[253, 255, 255, 255, 255, 255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn zigzag() {
+        for (value, encoded) in [
+            (0i64, &[0][..]),
+            (-1, &[1]),
+            (1, &[2]),
+            (-126, &[251, 251, 0]),
+            (i64::MIN, &[253, 255, 255, 255, 255, 255, 255, 255, 255]),
+        ] {
+            let (data, len) = encode_zigzag(value);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(
+                decode_zigzag::<i64>(encoded).unwrap(),
+                (value, encoded.len())
+            );
+        }
+
+        assert_eq!(decode_zigzag::<i16>(&[3]).unwrap(), (-2, 1));
+    }
+
+    #[test]
+    fn raw_bytes() {
+        assert_eq!(encode(u8::MAX).0[..2], [255, 0]);
+        assert_eq!(decode::<u8>(&[255, 1]).unwrap(), (255, 1));
+
+        assert_eq!(encode_zigzag(-2i8).0[..2], [0xFE, 0]);
+        assert_eq!(decode_zigzag::<i8>(&[0xFE]).unwrap(), (-2, 1));
+    }
+
+    #[test]
+    fn wide() {
+        for value in [
+            0,
+            250,
+            251,
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            u128::MAX,
+        ] {
+            let (data, len) = encode_u128(value);
+            assert_eq!(
+                decode_u128(&data[..len as usize]).unwrap(),
+                (value, len as usize)
+            );
+        }
+
+        let (data, len) = encode_u128(u128::MAX);
+        assert_eq!(len, 17);
+        assert_eq!(data[0], 254);
+        assert_eq!(data[1..], [255; 16]);
+
+        for value in [0, -1, i64::MIN as i128, i128::MIN, i128::MAX] {
+            let (data, len) = encode_i128(value);
+            assert_eq!(
+                decode_i128(&data[..len as usize]).unwrap(),
+                (value, len as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        // wider markers are accepted if the value fits, as in bincode 1
+        assert_eq!(decode::<u16>(&[252, 5, 0, 0, 0]).unwrap(), (5, 5));
+
+        assert!(matches!(
+            decode::<u16>(&[252, 0, 0, 1, 0]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode::<u64>(&[254, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode::<u64>(&[255]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode::<u64>(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode::<u64>(&[253, 0, 0]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_u128(&[254, 0]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+//! Varint formats of other Rust serialization crates.
+//!
+//! * [`bincode`]: the varint encoding of `bincode`, with marker bytes for wider integers.
+//! * [`postcard`]: the varint encoding of `postcard`, which is LEB128 with ZigZag encoding for
+//!   signed integers.
+//!
+//! In both formats, `u8` and `i8` are stored as single raw bytes rather than varints, which the
+//! generic functions here take into account. Both formats also support `u128` and `i128` values,
+//! which are handled by separate functions.
+
+pub mod bincode;
+pub mod postcard;
+
+/// Returns true if `T` is stored as a raw byte
+#[inline(always)]
+const fn is_raw<T>() -> bool {
+    core::mem::size_of::<T>() == 1
+}
@@ -0,0 +1,268 @@
+//! The varint encoding of `postcard`.
+//!
+//! Integers wider than 8 bits are stored as LEB128 varints, and signed integers are ZigZag
+//! encoded first. Varints that are too long for their type, or that have bits set beyond the width of
+//! their type, are rejected with [`VarIntDecodeError::Overflow`], as in postcard.
+
+use super::is_raw;
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+/// The maximum length of a `u128` or `i128` varint
+const MAX_U128_LEN: usize = 19;
+
+/// Encodes a single number in postcard format. Requires SSE2 support.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// number.
+///
+/// # Examples
+/// ```
+/// use varint_simd::compat::postcard::encode;
+///
+/// assert_eq!(encode::<u32>(1337).1, 2);
+/// assert_eq!(encode::<u8>(200).1, 1);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
+    if is_raw::<T>() {
+        let mut res = [0u8; 16];
+        res[0] = num.to_u64() as u8;
+        (res, 1)
+    } else {
+        crate::encode(num)
+    }
+}
+
+/// Encodes a single signed integer in postcard format, using ZigZag encoding for integers wider
+/// than 8 bits. See also: [`encode`]
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_zigzag<T: SignedVarIntTarget>(num: T) -> ([u8; 16], u8) {
+    if is_raw::<T>() {
+        let mut res = [0u8; 16];
+        res[0] = num.to_i64() as u8;
+        (res, 1)
+    } else {
+        crate::encode_zigzag(num)
+    }
+}
+
+/// Encodes a `u128` in postcard format.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint (maximum 19 bytes).
+#[inline]
+pub fn encode_u128(mut num: u128) -> ([u8; MAX_U128_LEN], u8) {
+    let mut res = [0u8; MAX_U128_LEN];
+    let mut len = 0;
+
+    loop {
+        let byte = (num & 0x7f) as u8;
+        num >>= 7;
+        if num == 0 {
+            res[len] = byte;
+            return (res, len as u8 + 1);
+        }
+
+        res[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Encodes an `i128` in postcard format, using ZigZag encoding.
+///
+/// See also: [`encode_u128`]
+#[inline]
+pub fn encode_i128(num: i128) -> ([u8; MAX_U128_LEN], u8) {
+    encode_u128(((num << 1) ^ (num >> 127)) as u128)
+}
+
+/// Decodes a single number in postcard format from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{compat::postcard::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(decode::<u32>(&[185, 10])?, (1337, 2));
+///     assert_eq!(decode::<u8>(&[200])?, (200, 1));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode<T: VarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    if is_raw::<T>() {
+        let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        return Ok((T::cast_u32(first as u32), 1));
+    }
+
+    let (num, len) = crate::decode::<T>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num, len))
+    }
+}
+
+/// Decodes a single signed integer in postcard format from the input slice, using ZigZag
+/// encoding for integers wider than 8 bits. See also: [`decode`]
+#[inline]
+pub fn decode_zigzag<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    if is_raw::<T>() {
+        let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+        return Ok((T::cast_i64(first as i8 as i64), 1));
+    }
+
+    decode::<T::Unsigned>(bytes).map(|r| (r.0.unzigzag(), r.1))
+}
+
+/// Decodes a `u128` in postcard format from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+#[inline]
+pub fn decode_u128(bytes: &[u8]) -> Result<(u128, usize), VarIntDecodeError> {
+    // Most values fit in a u64, so try the faster path first
+    if let Ok((num, len)) = decode::<u64>(bytes) {
+        return Ok((num as u128, len));
+    }
+
+    let mut num = 0u128;
+    for (i, byte) in bytes.iter().take(MAX_U128_LEN).enumerate() {
+        // Only 2 bits remain for the last byte
+        if i == MAX_U128_LEN - 1 && *byte > 0b11 {
+            return Err(VarIntDecodeError::Overflow);
+        }
+
+        num |= ((byte & 0x7f) as u128) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok((num, i + 1));
+        }
+    }
+
+    Err(VarIntDecodeError::NotEnoughBytes)
+}
+
+/// Decodes an `i128` in postcard format from the input slice, using ZigZag encoding.
+///
+/// See also: [`decode_u128`]
+#[inline]
+pub fn decode_i128(bytes: &[u8]) -> Result<(i128, usize), VarIntDecodeError> {
+    decode_u128(bytes).map(|(num, len)| (((num >> 1) as i128) ^ -((num & 1) as i128), len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_bytes() {
+        assert_eq!(
+            encode(200u8),
+            ([200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 1)
+        );
+        assert_eq!(decode::<u8>(&[200, 1]).unwrap(), (200, 1));
+
+        assert_eq!(encode_zigzag(-2i8).0[0], 0xFE);
+        assert_eq!(decode_zigzag::<i8>(&[0xFE]).unwrap(), (-2, 1));
+        assert_eq!(decode_zigzag::<i8>(&[0x80]).unwrap(), (i8::MIN, 1));
+    }
+
+    #[test]
+    fn varints() {
+        for (value, encoded) in [
+            (0u64, &[0x00][..]),
+            (127, &[0x7F]),
+            (128, &[0x80, 0x01]),
+            (16384, &[0x80, 0x80, 0x01]),
+            (
+                u64::MAX,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+        ] {
+            let (data, len) = encode(value);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(decode::<u64>(encoded).unwrap(), (value, encoded.len()));
+            assert_eq!(
+                decode_u128(encoded).unwrap(),
+                (value as u128, encoded.len())
+            );
+        }
+
+        let (data, len) = encode_zigzag(-1i16);
+        assert_eq!(&data[..len as usize], &[0x01]);
+        assert_eq!(decode_zigzag::<i16>(&[0x01]).unwrap(), (-1, 1));
+        assert_eq!(decode_zigzag::<i32>(&[0x03]).unwrap(), (-2, 1));
+    }
+
+    #[test]
+    fn wide() {
+        let (data, len) = encode_u128(u128::MAX);
+        assert_eq!(len, 19);
+        assert_eq!(data[..18], [0xFF; 18]);
+        assert_eq!(data[18], 0x03);
+
+        for shift in 0..128 {
+            for value in [1u128 << shift, (1u128 << shift) - 1] {
+                let (data, len) = encode_u128(value);
+                assert_eq!(
+                    decode_u128(&data[..len as usize]).unwrap(),
+                    (value, len as usize)
+                );
+
+                if let Ok(value) = u64::try_from(value) {
+                    assert_eq!(encode(value).0[..len as usize], data[..len as usize]);
+                }
+            }
+        }
+
+        for value in [0, -1, 1, i64::MIN as i128, i128::MIN, i128::MAX] {
+            let (data, len) = encode_i128(value);
+            assert_eq!(
+                decode_i128(&data[..len as usize]).unwrap(),
+                (value, len as usize)
+            );
+        }
+        assert_eq!(decode_i128(&[0x03]).unwrap(), (-2, 1));
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(
+            decode::<u32>(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        let mut data = [0xFF; 19];
+        data[18] = 0x04;
+        assert!(matches!(
+            decode_u128(&data),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_u128(&[0x80; 20]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode::<u8>(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode::<u32>(&[0x80]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_u128(&[0x80; 12]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use core::cmp::min;
 use crate::num::{SignedVarIntTarget, VarIntTarget};
 use crate::VarIntDecodeError;
 
-mod lookup;
+pub(crate) mod lookup;
 
 /// Decodes a single varint from the input slice.
 ///
@@ -98,6 +98,175 @@ pub fn decode_zigzag<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize),
     decode::<T::Unsigned>(bytes).map(|r| (r.0.unzigzag(), r.1))
 }
 
+/// Counts the varints in the input slice, by counting the bytes without a continuation bit. A
+/// truncated varint at the end of the slice is not counted, and the lengths of the varints are not
+/// checked.
+///
+/// The most significant bits of 32 bytes at a time are gathered with `_mm256_movemask_epi8` when
+/// AVX2 is available, or 16 bytes at a time with SSE2, and counted with `popcnt`.
+///
+/// See also: [`count_varints_checked`]
+///
+/// # Examples
+/// ```
+/// use varint_simd::count_varints;
+///
+/// assert_eq!(count_varints(&[185, 10, 1, 128, 128, 128, 128, 15]), 3);
+/// assert_eq!(count_varints(&[185, 10, 128]), 1);
+/// ```
+#[inline]
+pub fn count_varints(bytes: &[u8]) -> usize {
+    let mut count = 0;
+
+    let mut chunks = bytes.chunks_exact(COUNT_CHUNK_LEN);
+    for chunk in &mut chunks {
+        // SAFETY: the chunk is exactly COUNT_CHUNK_LEN bytes long
+        let mask = unsafe { continuation_mask(chunk.as_ptr()) };
+        count += COUNT_CHUNK_LEN - mask.count_ones() as usize;
+    }
+
+    count + chunks.remainder().iter().filter(|b| **b < 0x80).count()
+}
+
+/// Counts the varints in the input slice like [`count_varints`], but also checks that no varint
+/// is longer than `T::MAX_VARINT_BYTES`, and that the slice does not end with a truncated varint.
+///
+/// Only the lengths of the varints are checked, so a varint of the maximum length may still hold
+/// a value too large for `T`.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{count_varints_checked, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(count_varints_checked::<u32>(&[185, 10, 1, 128, 128, 128, 128, 15])?, 3);
+///     assert!(matches!(
+///         count_varints_checked::<u16>(&[185, 10, 1, 128, 128, 128, 128, 15]),
+///         Err(VarIntDecodeError::Overflow)
+///     ));
+///     assert!(matches!(
+///         count_varints_checked::<u32>(&[185, 10, 128]),
+///         Err(VarIntDecodeError::NotEnoughBytes)
+///     ));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn count_varints_checked<T: VarIntTarget>(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    let mut count = 0;
+    // The continuation bits of the last 64 bytes, with the most recent byte in the highest bit
+    let mut history = 0u64;
+
+    let mut chunks = bytes.chunks_exact(COUNT_CHUNK_LEN);
+    for chunk in &mut chunks {
+        // SAFETY: the chunk is exactly COUNT_CHUNK_LEN bytes long
+        let mask = unsafe { continuation_mask(chunk.as_ptr()) };
+        count += COUNT_CHUNK_LEN - mask.count_ones() as usize;
+
+        history = (history >> COUNT_CHUNK_LEN) | (mask << (64 - COUNT_CHUNK_LEN));
+        check_continuation_runs::<T>(history)?;
+    }
+
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        // Padding with terminators cannot create a run of continuation bytes
+        let mut data = [0u8; COUNT_CHUNK_LEN];
+        data[..remainder.len()].copy_from_slice(remainder);
+
+        let mask = unsafe { continuation_mask(data.as_ptr()) };
+        count += remainder.len() - mask.count_ones() as usize;
+
+        history = (history >> COUNT_CHUNK_LEN) | (mask << (64 - COUNT_CHUNK_LEN));
+        check_continuation_runs::<T>(history)?;
+    }
+
+    if bytes.last().is_some_and(|b| *b >= 0x80) {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    Ok(count)
+}
+
+const COUNT_CHUNK_LEN: usize = if cfg!(target_feature = "avx2") {
+    32
+} else {
+    16
+};
+
+/// Returns the most significant bits of the next `COUNT_CHUNK_LEN` bytes, with the first byte in
+/// the lowest bit.
+///
+/// # Safety
+/// There must be at least `COUNT_CHUNK_LEN` bytes of allocated memory after the start of the
+/// pointer.
+#[inline(always)]
+unsafe fn continuation_mask(bytes: *const u8) -> u64 {
+    #[cfg(target_feature = "avx2")]
+    {
+        let b = _mm256_loadu_si256(bytes as *const __m256i);
+        _mm256_movemask_epi8(b) as u32 as u64
+    }
+
+    #[cfg(all(target_feature = "sse2", not(target_feature = "avx2")))]
+    {
+        let b = _mm_loadu_si128(bytes as *const __m128i);
+        _mm_movemask_epi8(b) as u32 as u64
+    }
+
+    #[cfg(not(target_feature = "sse2"))]
+    {
+        let mut mask = 0;
+        for i in 0..COUNT_CHUNK_LEN {
+            mask |= ((*bytes.add(i) >> 7) as u64) << i;
+        }
+        mask
+    }
+}
+
+/// Checks for a run of `T::MAX_VARINT_BYTES` continuation bits, which would make a varint too
+/// long. The history covers at least 32 bytes before the latest chunk, so a run that reaches into
+/// the latest chunk is always found.
+#[inline(always)]
+fn check_continuation_runs<T: VarIntTarget>(history: u64) -> Result<(), VarIntDecodeError> {
+    let mut runs = history;
+    for i in 1..T::MAX_VARINT_BYTES {
+        runs &= history >> i;
+    }
+
+    if runs != 0 {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok(())
+    }
+}
+
+/// Decodes adjacent varints until the end of the input slice, passing each one to `push`, which
+/// returns false if there is no room for it. Returns the number of values decoded.
+#[inline]
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+pub(crate) fn decode_packed<T: VarIntTarget>(
+    bytes: &[u8],
+    mut push: impl FnMut(T) -> bool,
+) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+    let mut count = 0;
+
+    while pos < bytes.len() {
+        let (num, len) = decode::<T>(&bytes[pos..])?;
+        pos += len;
+        if pos > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        if !push(num) {
+            return Err(VarIntDecodeError::CapacityExceeded);
+        }
+        count += 1;
+    }
+
+    Ok(count)
+}
+
 /// Decodes the length of the next integer
 ///
 /// # Safety
@@ -190,7 +359,10 @@ pub unsafe fn decode_unsafe<T: VarIntTarget>(bytes: *const u8) -> (T, usize) {
         // let varint_part0 = b0 & !(0xffffffffffffffff << len0.min(63));
         // let varint_part1 = b1 & !(0xffffffffffffffff << (((msbs0 == 0) as u32) * len1.min(63)));
 
-        let num = T::vector_to_num(core::mem::transmute([varint_part0, varint_part1]));
+        let num = T::vector_to_num(core::mem::transmute::<[u64; 2], [u8; 16]>([
+            varint_part0,
+            varint_part1,
+        ]));
         let len = if msbs0 == 0 { len1 + 64 } else { len0 } / 8;
 
         (num, len as usize)
@@ -279,8 +451,8 @@ pub unsafe fn decode_two_unsafe<T: VarIntTarget, U: VarIntTarget>(
         first_num = T::cast_u32(x[0]);
         second_num = U::cast_u32(x[2]);
     } else {
-        first_num = T::vector_to_num(core::mem::transmute(first));
-        second_num = U::vector_to_num(core::mem::transmute(second));
+        first_num = T::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(first));
+        second_num = U::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(second));
     }
 
     (first_num, second_num, first_len as u8, second_len as u8)
@@ -324,8 +496,10 @@ unsafe fn decode_two_u32_unsafe<T: VarIntTarget, U: VarIntTarget>(
         first_num = T::cast_u32(x[0]);
         second_num = U::cast_u32(x[2]);
     } else {
-        first_num = T::vector_to_num(core::mem::transmute(comb));
-        second_num = U::vector_to_num(core::mem::transmute(_mm_bsrli_si128(comb, 8)));
+        first_num = T::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(comb));
+        second_num = U::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(_mm_bsrli_si128(
+            comb, 8,
+        )));
     }
 
     (first_num, second_num, first_len, second_len)
@@ -360,7 +534,7 @@ unsafe fn dual_u16_stage2(comb: __m128i) -> __m128i {
 }
 
 #[inline(always)]
-unsafe fn dual_u32_stage2(comb: __m128i) -> __m128i {
+pub(crate) unsafe fn dual_u32_stage2(comb: __m128i) -> __m128i {
     _mm_or_si128(
         _mm_or_si128(
             _mm_and_si128(comb, _mm_set_epi64x(0x000000000000007f, 0x000000000000007f)),
@@ -516,8 +690,8 @@ pub unsafe fn decode_two_wide_unsafe<T: VarIntTarget, U: VarIntTarget>(
         first_num = T::cast_u64(_mm_extract_epi64(x, 0) as u64);
         second_num = U::cast_u64(_mm_extract_epi64(x, 1) as u64);
     } else {
-        first_num = T::vector_to_num(core::mem::transmute(first));
-        second_num = U::vector_to_num(core::mem::transmute(second));
+        first_num = T::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(first));
+        second_num = U::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(second));
     }
 
     (first_num, second_num, first_len as u8, second_len as u8)
@@ -654,10 +828,10 @@ pub unsafe fn decode_four_unsafe<
         third_num = V::cast_u32(x[2]);
         fourth_num = W::cast_u32(x[3]);
     } else {
-        first_num = T::vector_to_num(core::mem::transmute(first));
-        second_num = U::vector_to_num(core::mem::transmute(second));
-        third_num = V::vector_to_num(core::mem::transmute(third));
-        fourth_num = W::vector_to_num(core::mem::transmute(fourth));
+        first_num = T::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(first));
+        second_num = U::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(second));
+        third_num = V::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(third));
+        fourth_num = W::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(fourth));
     }
 
     (
@@ -741,10 +915,16 @@ unsafe fn decode_four_u16_unsafe<
         third_num = V::cast_u32(x[2]);
         fourth_num = W::cast_u32(x[3]);
     } else {
-        first_num = T::vector_to_num(core::mem::transmute(comb));
-        second_num = U::vector_to_num(core::mem::transmute(_mm_bsrli_si128(comb, 4)));
-        third_num = V::vector_to_num(core::mem::transmute(_mm_bsrli_si128(comb, 8)));
-        fourth_num = W::vector_to_num(core::mem::transmute(_mm_bsrli_si128(comb, 12)));
+        first_num = T::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(comb));
+        second_num = U::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(_mm_bsrli_si128(
+            comb, 4,
+        )));
+        third_num = V::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(_mm_bsrli_si128(
+            comb, 8,
+        )));
+        fourth_num = W::vector_to_num(core::mem::transmute::<__m128i, [u8; 16]>(_mm_bsrli_si128(
+            comb, 12,
+        )));
     }
 
     (
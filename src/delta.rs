@@ -0,0 +1,468 @@
+//! Delta coding of `u32` and `i32` sequences as standard varints.
+//!
+//! Each value is stored as its difference from the previous value, starting from zero, so sorted
+//! sequences such as IDs, timestamps and posting lists encode to mostly short varints. The
+//! ZigZag variants also store negative differences compactly, for sequences that are only mostly
+//! sorted. Differences wrap around, so every sequence can be encoded.
+//!
+//! Decoding is done with [`masked_vbyte`](crate::masked_vbyte), and when SSSE3 is available each
+//! vector of decoded differences is summed with a SIMD prefix sum before it is stored.
+//!
+//! For timestamps and other `i64` series sampled at a mostly regular interval, [`encode_dod_i64`]
+//! stores the differences between consecutive differences instead, as in the Gorilla time series
+//! database. The first value is stored as is, followed by the first difference, and then the
+//! delta-of-delta values, all as varints in ZigZag format. A perfectly regular series encodes to
+//! one byte per value.
+//!
+//! See: Tuomas Pelkonen et al. - Gorilla: A Fast, Scalable, In-Memory Time Series Database,
+//! Proceedings of the VLDB Endowment 8(12), 2015: <https://www.vldb.org/pvldb/vol8/p1816-teller.pdf>
+//!
+//! # Examples
+//! ```
+//! use varint_simd::{delta, VarIntDecodeError};
+//!
+//! fn main() -> Result<(), VarIntDecodeError> {
+//!     let mut encoded = [0u8; delta::max_encoded_len(4)];
+//!     let len = delta::encode_delta(&[1000, 1001, 1005, 1300], &mut encoded);
+//!     assert_eq!(&encoded[..len], &[232, 7, 1, 4, 167, 2]);
+//!
+//!     let mut decoded = [0u32; 4];
+//!     assert_eq!(delta::decode_delta(&encoded[..len], &mut decoded)?, len);
+//!     assert_eq!(decoded, [1000, 1001, 1005, 1300]);
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(all(target_arch = "x86", target_feature = "ssse3"))]
+use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "ssse3"))]
+use core::arch::x86_64::*;
+
+use crate::masked_vbyte::decode_inner;
+use crate::num::VarIntTarget;
+use crate::VarIntDecodeError;
+#[cfg(target_feature = "ssse3")]
+use crate::{decode_four_unsafe, decode_two_unsafe};
+
+/// Returns the maximum number of bytes needed to encode `count` values.
+#[inline]
+pub const fn max_encoded_len(count: usize) -> usize {
+    count * u32::MAX_VARINT_BYTES as usize
+}
+
+/// Encodes a slice of numbers as varints of the differences between consecutive values, and
+/// writes the resulting data to the output slice. Returns the number of bytes written.
+///
+/// See also: [`max_encoded_len`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_delta(values: &[u32], out: &mut [u8]) -> usize {
+    let mut pos = 0;
+    let mut prev = 0u32;
+
+    for num in values {
+        pos += crate::encode_to_slice(num.wrapping_sub(prev), &mut out[pos..]) as usize;
+        prev = *num;
+    }
+
+    pos
+}
+
+/// Encodes a slice of signed numbers as varints of the differences between consecutive values in
+/// ZigZag format. Returns the number of bytes written.
+///
+/// See also: [`encode_delta`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_zigzag_delta(values: &[i32], out: &mut [u8]) -> usize {
+    let mut pos = 0;
+    let mut prev = 0i32;
+
+    for num in values {
+        let delta = u32::zigzag(num.wrapping_sub(prev));
+        pos += crate::encode_to_slice(delta, &mut out[pos..]) as usize;
+        prev = *num;
+    }
+
+    pos
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode_delta`]. Returns the
+/// number of bytes read.
+#[inline]
+pub fn decode_delta(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    decode_inner::<true, false, false>(bytes, out, 0)
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode_zigzag_delta`].
+/// Returns the number of bytes read.
+#[inline]
+pub fn decode_zigzag_delta(bytes: &[u8], out: &mut [i32]) -> Result<usize, VarIntDecodeError> {
+    // SAFETY: i32 and u32 have the same size and alignment, and every bit pattern is valid for both
+    let out = unsafe { core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut u32, out.len()) };
+    decode_inner::<true, true, false>(bytes, out, 0)
+}
+
+/// Encodes a slice of numbers as varints of the delta-of-delta values in ZigZag format, and
+/// appends the resulting data to `out`.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{delta, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let timestamps = [1_700_000_000, 1_700_000_060, 1_700_000_120, 1_700_000_181];
+///
+///     let mut encoded = Vec::new();
+///     delta::encode_dod_i64(&timestamps, &mut encoded);
+///     assert_eq!(&encoded[5..], &[120, 0, 2]);
+///
+///     let mut decoded = [0i64; 4];
+///     assert_eq!(delta::decode_dod_i64(&encoded, &mut decoded)?, encoded.len());
+///     assert_eq!(decoded, timestamps);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", any(target_feature = "sse2", doc)))]
+#[cfg_attr(rustc_nightly, doc(cfg(all(feature = "std", target_feature = "sse2"))))]
+pub fn encode_dod_i64(values: &[i64], out: &mut Vec<u8>) {
+    out.reserve(values.len());
+
+    let mut prev = 0i64;
+    let mut prev_delta = 0i64;
+    for (i, num) in values.iter().enumerate() {
+        let dod = if i == 0 {
+            *num
+        } else {
+            let delta = num.wrapping_sub(prev);
+            let dod = delta.wrapping_sub(prev_delta);
+            prev_delta = delta;
+            dod
+        };
+        prev = *num;
+
+        let (data, len) = crate::encode_zigzag(dod);
+        out.extend_from_slice(&data[..len as usize]);
+    }
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode_dod_i64`]. Returns the
+/// number of bytes read.
+///
+/// When SSSE3 is available, delta-of-delta values are decoded four at a time while they fit in
+/// two bytes each, or two at a time while they fit in four bytes each, and both levels of
+/// summing are done with SIMD. Longer values are decoded one at a time.
+#[inline]
+pub fn decode_dod_i64(bytes: &[u8], out: &mut [i64]) -> Result<usize, VarIntDecodeError> {
+    if out.is_empty() {
+        return Ok(0);
+    }
+
+    let (first, mut pos) = crate::decode_zigzag::<i64>(bytes)?;
+    if pos > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+    out[0] = first;
+
+    let mut prev = first;
+    let mut prev_delta = 0i64;
+    #[allow(unused_mut)]
+    let mut written = 1;
+
+    #[cfg(target_feature = "ssse3")]
+    {
+        let mut prev_vec = unsafe { _mm_set1_epi64x(prev) };
+        let mut prev_delta_vec = unsafe { _mm_set1_epi64x(prev_delta) };
+
+        while bytes.len() - pos >= 16 && out.len() - written >= 2 {
+            let ptr = bytes[pos..].as_ptr();
+            let dst = out[written..].as_mut_ptr();
+
+            // SAFETY: there are at least 16 bytes remaining
+            let (a, b, c, d, a_len, b_len, c_len, d_len, invalid) =
+                unsafe { decode_four_unsafe::<u16, u16, u16, u16>(ptr) };
+            if !invalid && out.len() - written >= 4 && a_len.max(b_len).max(c_len).max(d_len) <= 2 {
+                // SAFETY: there are at least 4 output slots remaining
+                unsafe {
+                    let lo = _mm_set_epi64x(b as i64, a as i64);
+                    let hi = _mm_set_epi64x(d as i64, c as i64);
+                    sum_dod_pair(lo, dst, &mut prev_vec, &mut prev_delta_vec);
+                    sum_dod_pair(hi, dst.add(2), &mut prev_vec, &mut prev_delta_vec);
+                }
+
+                written += 4;
+                pos += (a_len + b_len + c_len + d_len) as usize;
+                continue;
+            }
+
+            // SAFETY: there are at least 16 bytes remaining
+            let (a, b, a_len, b_len) = unsafe { decode_two_unsafe::<u32, u32>(ptr) };
+            if a_len <= 4 && b_len <= 4 {
+                // SAFETY: there are at least 2 output slots remaining
+                unsafe {
+                    let x = _mm_set_epi64x(b as i64, a as i64);
+                    sum_dod_pair(x, dst, &mut prev_vec, &mut prev_delta_vec);
+                }
+
+                written += 2;
+                pos += (a_len + b_len) as usize;
+                continue;
+            }
+
+            // At least 16 bytes remain, so the varint cannot be truncated
+            let (dod, len) = crate::decode_zigzag::<i64>(&bytes[pos..])?;
+            // SAFETY: every bit pattern is valid for both __m128i and [i64; 2]
+            let [prev_delta, _]: [i64; 2] = unsafe { core::mem::transmute(prev_delta_vec) };
+            let [prev, _]: [i64; 2] = unsafe { core::mem::transmute(prev_vec) };
+            let delta = prev_delta.wrapping_add(dod);
+            out[written] = prev.wrapping_add(delta);
+
+            prev_vec = unsafe { _mm_set1_epi64x(out[written]) };
+            prev_delta_vec = unsafe { _mm_set1_epi64x(delta) };
+            written += 1;
+            pos += len;
+        }
+
+        // SAFETY: every bit pattern is valid for both __m128i and [i64; 2]
+        let [last_delta, _]: [i64; 2] = unsafe { core::mem::transmute(prev_delta_vec) };
+        prev = out[written - 1];
+        prev_delta = last_delta;
+    }
+
+    for num in &mut out[written..] {
+        let (dod, len) = crate::decode_zigzag::<i64>(&bytes[pos..])?;
+        if pos + len > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        prev_delta = prev_delta.wrapping_add(dod);
+        prev = prev.wrapping_add(prev_delta);
+        *num = prev;
+
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+/// Converts two delta-of-delta values from ZigZag format, sums them twice starting from the
+/// running sums broadcast in `prev_delta` and `prev`, and stores the results.
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn sum_dod_pair(x: __m128i, out: *mut i64, prev: &mut __m128i, prev_delta: &mut __m128i) {
+    let sign = _mm_sub_epi64(_mm_setzero_si128(), _mm_and_si128(x, _mm_set1_epi64x(1)));
+    let dod = _mm_xor_si128(_mm_srli_epi64(x, 1), sign);
+
+    let delta = _mm_add_epi64(_mm_add_epi64(dod, _mm_bslli_si128(dod, 8)), *prev_delta);
+    *prev_delta = _mm_unpackhi_epi64(delta, delta);
+
+    let value = _mm_add_epi64(_mm_add_epi64(delta, _mm_bslli_si128(delta, 8)), *prev);
+    *prev = _mm_unpackhi_epi64(value, value);
+
+    _mm_storeu_si128(out as *mut __m128i, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check(values: &[u32]) {
+        let mut expected = vec![];
+        let mut prev = 0u32;
+        for num in values {
+            let (data, len) = crate::encode(num.wrapping_sub(prev));
+            expected.extend_from_slice(&data[..len as usize]);
+            prev = *num;
+        }
+
+        let mut encoded = vec![0u8; max_encoded_len(values.len()) + 16];
+        let len = encode_delta(values, &mut encoded);
+        assert_eq!(&encoded[..len], &expected[..]);
+
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode_delta(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        // with padding, so the SIMD path is taken until the end
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode_delta(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        let signed: Vec<i32> = values.iter().map(|num| *num as i32).collect();
+        let len = encode_zigzag_delta(&signed, &mut encoded);
+        let mut decoded = vec![0i32; values.len()];
+        assert_eq!(decode_zigzag_delta(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, signed);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in 0..40 {
+            let values: Vec<u32> = (0..count)
+                .map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+                .collect();
+            check(&values);
+        }
+
+        check(&[0, u32::MAX, 0, u32::MAX, 1, 2, 3]);
+        check(&[u32::MAX; 100]);
+    }
+
+    #[test]
+    fn roundtrip_sorted() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        // Gaps of every varint length, so each kind of SIMD block is exercised
+        for bits in [7, 8, 14, 15, 21, 22, 28] {
+            let mut values: Vec<u32> = (0..1000)
+                .map(|_| rng.gen::<u32>() >> (32 - rng.gen_range(1..=bits)))
+                .collect();
+            let mut sum = 0u32;
+            for num in &mut values {
+                sum = sum.wrapping_add(*num);
+                *num = sum;
+            }
+            check(&values);
+        }
+
+        let mut values: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+        values.sort_unstable();
+        check(&values);
+    }
+
+    #[test]
+    fn zigzag_unsorted() {
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let values: Vec<i32> = (0..1000)
+            .map(|i| 1000 * i + rng.gen_range(-3000..3000))
+            .collect();
+
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode_zigzag_delta(&values, &mut encoded);
+        assert!(len < 2 * values.len() + 8);
+
+        let mut decoded = vec![0i32; values.len()];
+        assert_eq!(decode_zigzag_delta(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+    }
+
+    fn check_dod(values: &[i64]) {
+        let mut expected = vec![];
+        let mut prev = 0i64;
+        let mut prev_delta = 0i64;
+        for (i, num) in values.iter().enumerate() {
+            let delta = num.wrapping_sub(prev);
+            let dod = if i == 0 {
+                *num
+            } else {
+                delta.wrapping_sub(prev_delta)
+            };
+            if i > 0 {
+                prev_delta = delta;
+            }
+            prev = *num;
+
+            let (data, len) = crate::encode_zigzag(dod);
+            expected.extend_from_slice(&data[..len as usize]);
+        }
+
+        let mut encoded = vec![];
+        encode_dod_i64(values, &mut encoded);
+        assert_eq!(encoded, expected);
+
+        let mut decoded = vec![0i64; values.len()];
+        assert_eq!(
+            decode_dod_i64(&encoded, &mut decoded).unwrap(),
+            encoded.len()
+        );
+        assert_eq!(decoded, values);
+
+        // with padding, so the SIMD path is taken until the end
+        encoded.extend_from_slice(&[0xAB; 16]);
+        let mut decoded = vec![0i64; values.len()];
+        assert_eq!(
+            decode_dod_i64(&encoded, &mut decoded).unwrap(),
+            expected.len()
+        );
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn roundtrip_dod() {
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for count in 0..40 {
+            let values: Vec<i64> = (0..count)
+                .map(|_| rng.gen::<i64>() >> rng.gen_range(0..64))
+                .collect();
+            check_dod(&values);
+        }
+
+        // Jitter of every varint length around a regular interval
+        for bits in [1, 6, 7, 13, 14, 20, 27, 28, 34, 63] {
+            let mut ts = 1_700_000_000_000i64;
+            let values: Vec<i64> = (0..1000)
+                .map(|_| {
+                    let jitter = if rng.gen_bool(0.8) {
+                        0
+                    } else {
+                        rng.gen::<i64>() >> (64 - bits)
+                    };
+                    ts = ts.wrapping_add(1000 + jitter);
+                    ts
+                })
+                .collect();
+            check_dod(&values);
+        }
+
+        check_dod(&[i64::MIN, i64::MAX, 0, i64::MAX, i64::MIN, 1, 2, 3]);
+        check_dod(&[i64::MAX; 100]);
+    }
+
+    #[test]
+    fn dod_regular() {
+        let values: Vec<i64> = (0..1000).map(|i| 1_700_000_000 + 60 * i).collect();
+
+        let mut encoded = vec![];
+        encode_dod_i64(&values, &mut encoded);
+        // the first value takes 5 bytes, and the first difference 1 byte
+        assert_eq!(encoded.len(), values.len() + 4);
+        check_dod(&values);
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut out = [0i64; 3];
+        assert!(matches!(
+            decode_dod_i64(&[2, 2], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_dod_i64(&[], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+
+        let mut out = [0u32; 2];
+        assert!(matches!(
+            decode_delta(&[1], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_delta(&[1, 0x80], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
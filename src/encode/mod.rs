@@ -86,7 +86,10 @@ pub unsafe fn encode_unsafe<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
 
         let merged = stage1 | (msbs & msbmask);
 
-        (core::mem::transmute([merged, 0]), bytes_needed as u8)
+        (
+            core::mem::transmute::<[u64; 2], [u8; 16]>([merged, 0]),
+            bytes_needed as u8,
+        )
     } else {
         // Break the number into 7-bit parts and spread them out into a vector
         let stage1: __m128i = core::mem::transmute(num.num_to_vector_stage1());
@@ -113,6 +116,6 @@ pub unsafe fn encode_unsafe<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
         // Merge the MSB bits into the vector
         let merged = _mm_or_si128(stage1, msbmask);
 
-        (core::mem::transmute(merged), bytes)
+        (core::mem::transmute::<__m128i, [u8; 16]>(merged), bytes)
     }
 }
@@ -0,0 +1,267 @@
+//! Frame-of-reference coding of `u32` sequences in blocks of varints.
+//!
+//! The values are split into blocks of [`BLOCK_LEN`] values, the last of which may be shorter.
+//! Each block starts with a header of two varints: the number of bytes in the rest of the block,
+//! and the smallest value in the block. The rest of the block holds every value minus that
+//! minimum as a varint, so values that are close together encode to short varints even when they
+//! are large. The length in the header lets blocks be skipped without decoding them. A block
+//! whose values do not take up exactly that length is rejected with
+//! [`VarIntDecodeError::Invalid`].
+//!
+//! The offsets are decoded with [`masked_vbyte`](crate::masked_vbyte), and when SSSE3 is
+//! available the block minimum is added to each vector of decoded values before it is stored.
+//!
+//! # Examples
+//! ```
+//! use varint_simd::{frame_of_reference, VarIntDecodeError};
+//!
+//! fn main() -> Result<(), VarIntDecodeError> {
+//!     let mut encoded = [0u8; frame_of_reference::max_encoded_len(3)];
+//!     let len = frame_of_reference::encode(&[1000, 1003, 1001], &mut encoded);
+//!     assert_eq!(&encoded[..len], &[3, 232, 7, 0, 3, 1]);
+//!
+//!     let mut decoded = [0u32; 3];
+//!     assert_eq!(frame_of_reference::decode(&encoded[..len], &mut decoded)?, len);
+//!     assert_eq!(decoded, [1000, 1003, 1001]);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::masked_vbyte::decode_inner;
+use crate::num::encoded_len;
+use crate::VarIntDecodeError;
+
+/// The number of values in every block except the last.
+pub const BLOCK_LEN: usize = 128;
+
+/// Returns the maximum number of bytes needed to encode `count` values.
+#[inline]
+pub const fn max_encoded_len(count: usize) -> usize {
+    // A block holds at most 640 bytes of offsets, so its length always fits in 2 bytes
+    count.div_ceil(BLOCK_LEN) * 7 + count * 5
+}
+
+/// Encodes a slice of numbers in blocks, and writes the resulting data to the output slice.
+/// Returns the number of bytes written.
+///
+/// See also: [`max_encoded_len`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode(values: &[u32], out: &mut [u8]) -> usize {
+    let mut pos = 0;
+
+    for block in values.chunks(BLOCK_LEN) {
+        let min = block.iter().copied().min().unwrap_or(0);
+        let len: usize = block.iter().map(|num| encoded_len(num - min)).sum();
+
+        pos += crate::encode_to_slice(len as u32, &mut out[pos..]) as usize;
+        pos += crate::encode_to_slice(min, &mut out[pos..]) as usize;
+        for num in block {
+            pos += crate::encode_to_slice(num - min, &mut out[pos..]) as usize;
+        }
+    }
+
+    pos
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode`]. Returns the number
+/// of bytes read.
+#[inline]
+pub fn decode(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+
+    for block in out.chunks_mut(BLOCK_LEN) {
+        pos += decode_block_at(&bytes[pos..], block)?;
+    }
+
+    Ok(pos)
+}
+
+/// Decodes the block at `index` from the input slice, as encoded by [`encode`]. The length of
+/// `out` must be the number of values in the block, which is [`BLOCK_LEN`] for every block except
+/// the last.
+///
+/// The preceding blocks are skipped by reading only their headers.
+///
+/// **Panics:** if `out` is longer than [`BLOCK_LEN`].
+///
+/// # Examples
+/// ```
+/// use varint_simd::{frame_of_reference, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let values: Vec<u32> = (0..300).map(|i| 5000 + i).collect();
+///     let mut encoded = vec![0u8; frame_of_reference::max_encoded_len(values.len())];
+///     frame_of_reference::encode(&values, &mut encoded);
+///
+///     // the third block holds the last 44 values
+///     let mut block = [0u32; 44];
+///     frame_of_reference::decode_block(&encoded, 2, &mut block)?;
+///     assert_eq!(block[..], values[256..]);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_block(bytes: &[u8], index: usize, out: &mut [u32]) -> Result<(), VarIntDecodeError> {
+    assert!(out.len() <= BLOCK_LEN, "block is too long");
+
+    let mut pos = 0;
+    for _ in 0..index {
+        let (len, len_len) = crate::decode::<u32>(&bytes[pos..])?;
+        pos += len_len;
+        if pos > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        // The minimum is only needed for the block being decoded
+        pos = pos
+            .saturating_add(crate::decode_len::<u32>(&bytes[pos..])?)
+            .saturating_add(len as usize);
+        if pos > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+    }
+
+    decode_block_at(&bytes[pos..], out)?;
+    Ok(())
+}
+
+/// Decodes a single block from the start of the input slice. Returns the number of bytes read.
+#[inline(always)]
+fn decode_block_at(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    let (len, mut pos) = crate::decode::<u32>(bytes)?;
+    if pos > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    let (min, min_len) = crate::decode::<u32>(&bytes[pos..])?;
+    pos += min_len;
+
+    let end = pos.saturating_add(len as usize);
+    if end > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    if decode_inner::<false, false, true>(&bytes[pos..end], out, min)? != len as usize {
+        return Err(VarIntDecodeError::Invalid);
+    }
+    Ok(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check(values: &[u32]) {
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(values, &mut encoded);
+
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        for (i, expected) in values.chunks(BLOCK_LEN).enumerate() {
+            let mut block = vec![0u32; expected.len()];
+            decode_block(&encoded[..len], i, &mut block).unwrap();
+            assert_eq!(block, expected);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in [0, 1, 2, 15, 16, 17, 127, 128, 129, 1000] {
+            let values: Vec<u32> = (0..count)
+                .map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+                .collect();
+            check(&values);
+        }
+
+        check(&[u32::MAX; 300]);
+        check(&[0, u32::MAX, 0, u32::MAX]);
+    }
+
+    #[test]
+    fn roundtrip_clustered() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        // Offsets of every varint length, so each kind of SIMD block is exercised
+        for bits in [1, 7, 8, 14, 15, 21, 22, 28, 31] {
+            let base = rng.gen::<u32>() >> 1;
+            let values: Vec<u32> = (0..1000)
+                .map(|_| base + (rng.gen::<u32>() >> (32 - bits)))
+                .collect();
+            check(&values);
+        }
+
+        let values: Vec<u32> = (0..1000)
+            .map(|_| rng.gen_range(3_000_000..3_000_100))
+            .collect();
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(&values, &mut encoded);
+        assert!(len < values.len() + 8 * values.len().div_ceil(BLOCK_LEN));
+    }
+
+    #[test]
+    fn encoded_len() {
+        for bits in 0..=32 {
+            let num = if bits == 32 {
+                u32::MAX
+            } else {
+                (1u32 << bits) - 1
+            };
+            assert_eq!(super::encoded_len(num), crate::encode(num).1 as usize);
+            if bits < 32 {
+                assert_eq!(
+                    super::encoded_len(num + 1),
+                    crate::encode(num + 1).1 as usize
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let values: Vec<u32> = (0..200).collect();
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(&values, &mut encoded);
+
+        let mut decoded = vec![0u32; values.len()];
+        assert!(matches!(
+            decode(&encoded[..len - 1], &mut decoded),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_block(&encoded[..len - 1], 1, &mut decoded[..72]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_block(&encoded[..len], 2, &mut decoded[..1]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let mut decoded = [0u32; 3];
+        assert!(matches!(
+            decode(&[4, 232, 7, 0, 3, 1, 5], &mut decoded),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        assert!(matches!(
+            decode_block(&[4, 232, 7, 0, 3, 1, 5], 0, &mut decoded),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        assert!(matches!(
+            decode(&[2, 232, 7, 0, 3, 1], &mut decoded),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,527 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[repr(align(16), C)]
+struct Align128<T>(T);
+
+pub static LOOKUP_GROUP_VEC: [__m128i; 256] = unsafe {
+    core::mem::transmute(Align128::<[u8; 4096]>([
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 255, 255, 255, 3, 255, 255, 255, // 1, 1, 1, 1
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 255, 255, 255, 4, 255, 255, 255, // 2, 1, 1, 1
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 255, 255, 255, 5, 255, 255, 255, // 3, 1, 1, 1
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 255, 255, 255, 6, 255, 255, 255, // 4, 1, 1, 1
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 255, 255, 255, 4, 255, 255, 255, // 1, 2, 1, 1
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 255, 255, 255, 5, 255, 255, 255, // 2, 2, 1, 1
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 255, 255, 255, 6, 255, 255, 255, // 3, 2, 1, 1
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 255, 255, 255, 7, 255, 255, 255, // 4, 2, 1, 1
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 255, 255, 255, 5, 255, 255, 255, // 1, 3, 1, 1
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 255, 255, 255, 6, 255, 255, 255, // 2, 3, 1, 1
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 255, 255, 255, 7, 255, 255, 255, // 3, 3, 1, 1
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 255, 255, 255, 8, 255, 255, 255, // 4, 3, 1, 1
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 255, 255, 255, 6, 255, 255, 255, // 1, 4, 1, 1
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 255, 255, 255, 7, 255, 255, 255, // 2, 4, 1, 1
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 255, 255, 255, 8, 255, 255, 255, // 3, 4, 1, 1
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 255, 255, 255, 9, 255, 255, 255, // 4, 4, 1, 1
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 255, 255, 4, 255, 255, 255, // 1, 1, 2, 1
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 255, 255, 5, 255, 255, 255, // 2, 1, 2, 1
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 255, 255, 6, 255, 255, 255, // 3, 1, 2, 1
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 255, 255, 7, 255, 255, 255, // 4, 1, 2, 1
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 255, 255, 5, 255, 255, 255, // 1, 2, 2, 1
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 255, 255, 6, 255, 255, 255, // 2, 2, 2, 1
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 255, 255, 7, 255, 255, 255, // 3, 2, 2, 1
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 255, 255, 8, 255, 255, 255, // 4, 2, 2, 1
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 255, 255, 6, 255, 255, 255, // 1, 3, 2, 1
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 255, 255, 7, 255, 255, 255, // 2, 3, 2, 1
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 255, 255, 8, 255, 255, 255, // 3, 3, 2, 1
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 255, 255, 9, 255, 255, 255, // 4, 3, 2, 1
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 255, 255, 7, 255, 255, 255, // 1, 4, 2, 1
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 255, 255, 8, 255, 255, 255, // 2, 4, 2, 1
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 255, 255, 9, 255, 255, 255, // 3, 4, 2, 1
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 10, 255, 255, 255, // 4, 4, 2, 1
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 255, 5, 255, 255, 255, // 1, 1, 3, 1
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 255, 6, 255, 255, 255, // 2, 1, 3, 1
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 255, 7, 255, 255, 255, // 3, 1, 3, 1
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 255, 8, 255, 255, 255, // 4, 1, 3, 1
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 255, 6, 255, 255, 255, // 1, 2, 3, 1
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 255, 7, 255, 255, 255, // 2, 2, 3, 1
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 255, 8, 255, 255, 255, // 3, 2, 3, 1
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 255, 9, 255, 255, 255, // 4, 2, 3, 1
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 255, 7, 255, 255, 255, // 1, 3, 3, 1
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 255, 8, 255, 255, 255, // 2, 3, 3, 1
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 255, 9, 255, 255, 255, // 3, 3, 3, 1
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 255, 10, 255, 255, 255, // 4, 3, 3, 1
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 255, 8, 255, 255, 255, // 1, 4, 3, 1
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 255, 9, 255, 255, 255, // 2, 4, 3, 1
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 255, 10, 255, 255, 255, // 3, 4, 3, 1
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 11, 255, 255, 255, // 4, 4, 3, 1
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 5, 6, 255, 255, 255, // 1, 1, 4, 1
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 6, 7, 255, 255, 255, // 2, 1, 4, 1
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 7, 8, 255, 255, 255, // 3, 1, 4, 1
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 8, 9, 255, 255, 255, // 4, 1, 4, 1
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 6, 7, 255, 255, 255, // 1, 2, 4, 1
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 7, 8, 255, 255, 255, // 2, 2, 4, 1
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 8, 9, 255, 255, 255, // 3, 2, 4, 1
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 9, 10, 255, 255, 255, // 4, 2, 4, 1
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 7, 8, 255, 255, 255, // 1, 3, 4, 1
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 8, 9, 255, 255, 255, // 2, 3, 4, 1
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 9, 10, 255, 255, 255, // 3, 3, 4, 1
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 10, 11, 255, 255, 255, // 4, 3, 4, 1
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 255, // 1, 4, 4, 1
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 255, 255, // 2, 4, 4, 1
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 10, 11, 255, 255, 255, // 3, 4, 4, 1
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 255, 255, 255, // 4, 4, 4, 1
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 255, 255, 255, 3, 4, 255, 255, // 1, 1, 1, 2
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 255, 255, 255, 4, 5, 255, 255, // 2, 1, 1, 2
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 255, 255, 255, 5, 6, 255, 255, // 3, 1, 1, 2
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 255, 255, 255, 6, 7, 255, 255, // 4, 1, 1, 2
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 255, 255, 255, 4, 5, 255, 255, // 1, 2, 1, 2
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 255, 255, 255, 5, 6, 255, 255, // 2, 2, 1, 2
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 255, 255, 255, 6, 7, 255, 255, // 3, 2, 1, 2
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 255, 255, 255, 7, 8, 255, 255, // 4, 2, 1, 2
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 255, 255, 255, 5, 6, 255, 255, // 1, 3, 1, 2
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 255, 255, 255, 6, 7, 255, 255, // 2, 3, 1, 2
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 255, 255, 255, 7, 8, 255, 255, // 3, 3, 1, 2
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 255, 255, 255, 8, 9, 255, 255, // 4, 3, 1, 2
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 255, 255, 255, 6, 7, 255, 255, // 1, 4, 1, 2
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 255, 255, 255, 7, 8, 255, 255, // 2, 4, 1, 2
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 255, 255, 255, 8, 9, 255, 255, // 3, 4, 1, 2
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 255, 255, 255, 9, 10, 255, 255, // 4, 4, 1, 2
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 255, 255, 4, 5, 255, 255, // 1, 1, 2, 2
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 255, 255, 5, 6, 255, 255, // 2, 1, 2, 2
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 255, 255, 6, 7, 255, 255, // 3, 1, 2, 2
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 255, 255, 7, 8, 255, 255, // 4, 1, 2, 2
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 255, 255, 5, 6, 255, 255, // 1, 2, 2, 2
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 255, 255, 6, 7, 255, 255, // 2, 2, 2, 2
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 255, 255, 7, 8, 255, 255, // 3, 2, 2, 2
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 255, 255, 8, 9, 255, 255, // 4, 2, 2, 2
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 255, 255, 6, 7, 255, 255, // 1, 3, 2, 2
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 255, 255, 7, 8, 255, 255, // 2, 3, 2, 2
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 255, 255, 8, 9, 255, 255, // 3, 3, 2, 2
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 255, 255, 9, 10, 255, 255, // 4, 3, 2, 2
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 255, 255, 7, 8, 255, 255, // 1, 4, 2, 2
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 255, 255, 8, 9, 255, 255, // 2, 4, 2, 2
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 255, 255, 9, 10, 255, 255, // 3, 4, 2, 2
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 10, 11, 255, 255, // 4, 4, 2, 2
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 255, 5, 6, 255, 255, // 1, 1, 3, 2
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 255, 6, 7, 255, 255, // 2, 1, 3, 2
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 255, 7, 8, 255, 255, // 3, 1, 3, 2
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 255, 8, 9, 255, 255, // 4, 1, 3, 2
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 255, 6, 7, 255, 255, // 1, 2, 3, 2
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 255, 7, 8, 255, 255, // 2, 2, 3, 2
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 255, 8, 9, 255, 255, // 3, 2, 3, 2
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 255, 9, 10, 255, 255, // 4, 2, 3, 2
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 255, 7, 8, 255, 255, // 1, 3, 3, 2
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 255, 8, 9, 255, 255, // 2, 3, 3, 2
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 255, 9, 10, 255, 255, // 3, 3, 3, 2
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 255, 10, 11, 255, 255, // 4, 3, 3, 2
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 255, 8, 9, 255, 255, // 1, 4, 3, 2
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 255, 9, 10, 255, 255, // 2, 4, 3, 2
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 255, 10, 11, 255, 255, // 3, 4, 3, 2
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 11, 12, 255, 255, // 4, 4, 3, 2
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 5, 6, 7, 255, 255, // 1, 1, 4, 2
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 6, 7, 8, 255, 255, // 2, 1, 4, 2
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 7, 8, 9, 255, 255, // 3, 1, 4, 2
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 8, 9, 10, 255, 255, // 4, 1, 4, 2
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 6, 7, 8, 255, 255, // 1, 2, 4, 2
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 7, 8, 9, 255, 255, // 2, 2, 4, 2
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 8, 9, 10, 255, 255, // 3, 2, 4, 2
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 9, 10, 11, 255, 255, // 4, 2, 4, 2
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 7, 8, 9, 255, 255, // 1, 3, 4, 2
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 8, 9, 10, 255, 255, // 2, 3, 4, 2
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 9, 10, 11, 255, 255, // 3, 3, 4, 2
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 10, 11, 12, 255, 255, // 4, 3, 4, 2
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 255, // 1, 4, 4, 2
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 255, 255, // 2, 4, 4, 2
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 255, 255, // 3, 4, 4, 2
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 255, 255, // 4, 4, 4, 2
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 255, 255, 255, 3, 4, 5, 255, // 1, 1, 1, 3
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 255, 255, 255, 4, 5, 6, 255, // 2, 1, 1, 3
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 255, 255, 255, 5, 6, 7, 255, // 3, 1, 1, 3
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 255, 255, 255, 6, 7, 8, 255, // 4, 1, 1, 3
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 255, 255, 255, 4, 5, 6, 255, // 1, 2, 1, 3
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 255, 255, 255, 5, 6, 7, 255, // 2, 2, 1, 3
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 255, 255, 255, 6, 7, 8, 255, // 3, 2, 1, 3
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 255, 255, 255, 7, 8, 9, 255, // 4, 2, 1, 3
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 255, 255, 255, 5, 6, 7, 255, // 1, 3, 1, 3
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 255, 255, 255, 6, 7, 8, 255, // 2, 3, 1, 3
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 255, 255, 255, 7, 8, 9, 255, // 3, 3, 1, 3
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 255, 255, 255, 8, 9, 10, 255, // 4, 3, 1, 3
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 255, 255, 255, 6, 7, 8, 255, // 1, 4, 1, 3
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 255, 255, 255, 7, 8, 9, 255, // 2, 4, 1, 3
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 255, 255, 255, 8, 9, 10, 255, // 3, 4, 1, 3
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 255, 255, 255, 9, 10, 11, 255, // 4, 4, 1, 3
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 255, 255, 4, 5, 6, 255, // 1, 1, 2, 3
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 255, 255, 5, 6, 7, 255, // 2, 1, 2, 3
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 255, 255, 6, 7, 8, 255, // 3, 1, 2, 3
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 255, 255, 7, 8, 9, 255, // 4, 1, 2, 3
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 255, 255, 5, 6, 7, 255, // 1, 2, 2, 3
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 255, 255, 6, 7, 8, 255, // 2, 2, 2, 3
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 255, 255, 7, 8, 9, 255, // 3, 2, 2, 3
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 255, 255, 8, 9, 10, 255, // 4, 2, 2, 3
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 255, 255, 6, 7, 8, 255, // 1, 3, 2, 3
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 255, 255, 7, 8, 9, 255, // 2, 3, 2, 3
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 255, 255, 8, 9, 10, 255, // 3, 3, 2, 3
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 255, 255, 9, 10, 11, 255, // 4, 3, 2, 3
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 255, 255, 7, 8, 9, 255, // 1, 4, 2, 3
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 255, 255, 8, 9, 10, 255, // 2, 4, 2, 3
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 255, 255, 9, 10, 11, 255, // 3, 4, 2, 3
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 10, 11, 12, 255, // 4, 4, 2, 3
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 255, 5, 6, 7, 255, // 1, 1, 3, 3
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 255, 6, 7, 8, 255, // 2, 1, 3, 3
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 255, 7, 8, 9, 255, // 3, 1, 3, 3
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 255, 8, 9, 10, 255, // 4, 1, 3, 3
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 255, 6, 7, 8, 255, // 1, 2, 3, 3
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 255, 7, 8, 9, 255, // 2, 2, 3, 3
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 255, 8, 9, 10, 255, // 3, 2, 3, 3
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 255, 9, 10, 11, 255, // 4, 2, 3, 3
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 255, 7, 8, 9, 255, // 1, 3, 3, 3
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 255, 8, 9, 10, 255, // 2, 3, 3, 3
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 255, 9, 10, 11, 255, // 3, 3, 3, 3
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 255, 10, 11, 12, 255, // 4, 3, 3, 3
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 255, 8, 9, 10, 255, // 1, 4, 3, 3
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 255, 9, 10, 11, 255, // 2, 4, 3, 3
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 255, 10, 11, 12, 255, // 3, 4, 3, 3
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 11, 12, 13, 255, // 4, 4, 3, 3
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 5, 6, 7, 8, 255, // 1, 1, 4, 3
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 6, 7, 8, 9, 255, // 2, 1, 4, 3
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 7, 8, 9, 10, 255, // 3, 1, 4, 3
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 8, 9, 10, 11, 255, // 4, 1, 4, 3
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 6, 7, 8, 9, 255, // 1, 2, 4, 3
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 7, 8, 9, 10, 255, // 2, 2, 4, 3
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 8, 9, 10, 11, 255, // 3, 2, 4, 3
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 9, 10, 11, 12, 255, // 4, 2, 4, 3
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 7, 8, 9, 10, 255, // 1, 3, 4, 3
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 8, 9, 10, 11, 255, // 2, 3, 4, 3
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 9, 10, 11, 12, 255, // 3, 3, 4, 3
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 10, 11, 12, 13, 255, // 4, 3, 4, 3
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 255, // 1, 4, 4, 3
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 255, // 2, 4, 4, 3
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 255, // 3, 4, 4, 3
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 255, // 4, 4, 4, 3
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 255, 255, 255, 3, 4, 5, 6, // 1, 1, 1, 4
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 255, 255, 255, 4, 5, 6, 7, // 2, 1, 1, 4
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 255, 255, 255, 5, 6, 7, 8, // 3, 1, 1, 4
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 255, 255, 255, 6, 7, 8, 9, // 4, 1, 1, 4
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 255, 255, 255, 4, 5, 6, 7, // 1, 2, 1, 4
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 255, 255, 255, 5, 6, 7, 8, // 2, 2, 1, 4
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 255, 255, 255, 6, 7, 8, 9, // 3, 2, 1, 4
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 255, 255, 255, 7, 8, 9, 10, // 4, 2, 1, 4
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 255, 255, 255, 5, 6, 7, 8, // 1, 3, 1, 4
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 255, 255, 255, 6, 7, 8, 9, // 2, 3, 1, 4
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 255, 255, 255, 7, 8, 9, 10, // 3, 3, 1, 4
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 255, 255, 255, 8, 9, 10, 11, // 4, 3, 1, 4
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 255, 255, 255, 6, 7, 8, 9, // 1, 4, 1, 4
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 255, 255, 255, 7, 8, 9, 10, // 2, 4, 1, 4
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 255, 255, 255, 8, 9, 10, 11, // 3, 4, 1, 4
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 255, 255, 255, 9, 10, 11, 12, // 4, 4, 1, 4
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 255, 255, 4, 5, 6, 7, // 1, 1, 2, 4
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 255, 255, 5, 6, 7, 8, // 2, 1, 2, 4
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 255, 255, 6, 7, 8, 9, // 3, 1, 2, 4
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 255, 255, 7, 8, 9, 10, // 4, 1, 2, 4
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 255, 255, 5, 6, 7, 8, // 1, 2, 2, 4
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 255, 255, 6, 7, 8, 9, // 2, 2, 2, 4
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 255, 255, 7, 8, 9, 10, // 3, 2, 2, 4
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 255, 255, 8, 9, 10, 11, // 4, 2, 2, 4
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 255, 255, 6, 7, 8, 9, // 1, 3, 2, 4
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 255, 255, 7, 8, 9, 10, // 2, 3, 2, 4
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 255, 255, 8, 9, 10, 11, // 3, 3, 2, 4
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 255, 255, 9, 10, 11, 12, // 4, 3, 2, 4
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 255, 255, 7, 8, 9, 10, // 1, 4, 2, 4
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 255, 255, 8, 9, 10, 11, // 2, 4, 2, 4
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 255, 255, 9, 10, 11, 12, // 3, 4, 2, 4
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 10, 11, 12, 13, // 4, 4, 2, 4
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 255, 5, 6, 7, 8, // 1, 1, 3, 4
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 255, 6, 7, 8, 9, // 2, 1, 3, 4
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 255, 7, 8, 9, 10, // 3, 1, 3, 4
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 255, 8, 9, 10, 11, // 4, 1, 3, 4
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 255, 6, 7, 8, 9, // 1, 2, 3, 4
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 255, 7, 8, 9, 10, // 2, 2, 3, 4
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 255, 8, 9, 10, 11, // 3, 2, 3, 4
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 255, 9, 10, 11, 12, // 4, 2, 3, 4
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 255, 7, 8, 9, 10, // 1, 3, 3, 4
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 255, 8, 9, 10, 11, // 2, 3, 3, 4
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 255, 9, 10, 11, 12, // 3, 3, 3, 4
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 255, 10, 11, 12, 13, // 4, 3, 3, 4
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 255, 8, 9, 10, 11, // 1, 4, 3, 4
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 255, 9, 10, 11, 12, // 2, 4, 3, 4
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 255, 10, 11, 12, 13, // 3, 4, 3, 4
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 255, 11, 12, 13, 14, // 4, 4, 3, 4
+        0, 255, 255, 255, 1, 255, 255, 255, 2, 3, 4, 5, 6, 7, 8, 9, // 1, 1, 4, 4
+        0, 1, 255, 255, 2, 255, 255, 255, 3, 4, 5, 6, 7, 8, 9, 10, // 2, 1, 4, 4
+        0, 1, 2, 255, 3, 255, 255, 255, 4, 5, 6, 7, 8, 9, 10, 11, // 3, 1, 4, 4
+        0, 1, 2, 3, 4, 255, 255, 255, 5, 6, 7, 8, 9, 10, 11, 12, // 4, 1, 4, 4
+        0, 255, 255, 255, 1, 2, 255, 255, 3, 4, 5, 6, 7, 8, 9, 10, // 1, 2, 4, 4
+        0, 1, 255, 255, 2, 3, 255, 255, 4, 5, 6, 7, 8, 9, 10, 11, // 2, 2, 4, 4
+        0, 1, 2, 255, 3, 4, 255, 255, 5, 6, 7, 8, 9, 10, 11, 12, // 3, 2, 4, 4
+        0, 1, 2, 3, 4, 5, 255, 255, 6, 7, 8, 9, 10, 11, 12, 13, // 4, 2, 4, 4
+        0, 255, 255, 255, 1, 2, 3, 255, 4, 5, 6, 7, 8, 9, 10, 11, // 1, 3, 4, 4
+        0, 1, 255, 255, 2, 3, 4, 255, 5, 6, 7, 8, 9, 10, 11, 12, // 2, 3, 4, 4
+        0, 1, 2, 255, 3, 4, 5, 255, 6, 7, 8, 9, 10, 11, 12, 13, // 3, 3, 4, 4
+        0, 1, 2, 3, 4, 5, 6, 255, 7, 8, 9, 10, 11, 12, 13, 14, // 4, 3, 4, 4
+        0, 255, 255, 255, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, // 1, 4, 4, 4
+        0, 1, 255, 255, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, // 2, 4, 4, 4
+        0, 1, 2, 255, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, // 3, 4, 4, 4
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, // 4, 4, 4, 4
+    ]))
+};
+
+pub static LOOKUP_GROUP_LEN: [u8; 256] = [
+    4,  // 0b00000000
+    5,  // 0b00000001
+    6,  // 0b00000010
+    7,  // 0b00000011
+    5,  // 0b00000100
+    6,  // 0b00000101
+    7,  // 0b00000110
+    8,  // 0b00000111
+    6,  // 0b00001000
+    7,  // 0b00001001
+    8,  // 0b00001010
+    9,  // 0b00001011
+    7,  // 0b00001100
+    8,  // 0b00001101
+    9,  // 0b00001110
+    10, // 0b00001111
+    5,  // 0b00010000
+    6,  // 0b00010001
+    7,  // 0b00010010
+    8,  // 0b00010011
+    6,  // 0b00010100
+    7,  // 0b00010101
+    8,  // 0b00010110
+    9,  // 0b00010111
+    7,  // 0b00011000
+    8,  // 0b00011001
+    9,  // 0b00011010
+    10, // 0b00011011
+    8,  // 0b00011100
+    9,  // 0b00011101
+    10, // 0b00011110
+    11, // 0b00011111
+    6,  // 0b00100000
+    7,  // 0b00100001
+    8,  // 0b00100010
+    9,  // 0b00100011
+    7,  // 0b00100100
+    8,  // 0b00100101
+    9,  // 0b00100110
+    10, // 0b00100111
+    8,  // 0b00101000
+    9,  // 0b00101001
+    10, // 0b00101010
+    11, // 0b00101011
+    9,  // 0b00101100
+    10, // 0b00101101
+    11, // 0b00101110
+    12, // 0b00101111
+    7,  // 0b00110000
+    8,  // 0b00110001
+    9,  // 0b00110010
+    10, // 0b00110011
+    8,  // 0b00110100
+    9,  // 0b00110101
+    10, // 0b00110110
+    11, // 0b00110111
+    9,  // 0b00111000
+    10, // 0b00111001
+    11, // 0b00111010
+    12, // 0b00111011
+    10, // 0b00111100
+    11, // 0b00111101
+    12, // 0b00111110
+    13, // 0b00111111
+    5,  // 0b01000000
+    6,  // 0b01000001
+    7,  // 0b01000010
+    8,  // 0b01000011
+    6,  // 0b01000100
+    7,  // 0b01000101
+    8,  // 0b01000110
+    9,  // 0b01000111
+    7,  // 0b01001000
+    8,  // 0b01001001
+    9,  // 0b01001010
+    10, // 0b01001011
+    8,  // 0b01001100
+    9,  // 0b01001101
+    10, // 0b01001110
+    11, // 0b01001111
+    6,  // 0b01010000
+    7,  // 0b01010001
+    8,  // 0b01010010
+    9,  // 0b01010011
+    7,  // 0b01010100
+    8,  // 0b01010101
+    9,  // 0b01010110
+    10, // 0b01010111
+    8,  // 0b01011000
+    9,  // 0b01011001
+    10, // 0b01011010
+    11, // 0b01011011
+    9,  // 0b01011100
+    10, // 0b01011101
+    11, // 0b01011110
+    12, // 0b01011111
+    7,  // 0b01100000
+    8,  // 0b01100001
+    9,  // 0b01100010
+    10, // 0b01100011
+    8,  // 0b01100100
+    9,  // 0b01100101
+    10, // 0b01100110
+    11, // 0b01100111
+    9,  // 0b01101000
+    10, // 0b01101001
+    11, // 0b01101010
+    12, // 0b01101011
+    10, // 0b01101100
+    11, // 0b01101101
+    12, // 0b01101110
+    13, // 0b01101111
+    8,  // 0b01110000
+    9,  // 0b01110001
+    10, // 0b01110010
+    11, // 0b01110011
+    9,  // 0b01110100
+    10, // 0b01110101
+    11, // 0b01110110
+    12, // 0b01110111
+    10, // 0b01111000
+    11, // 0b01111001
+    12, // 0b01111010
+    13, // 0b01111011
+    11, // 0b01111100
+    12, // 0b01111101
+    13, // 0b01111110
+    14, // 0b01111111
+    6,  // 0b10000000
+    7,  // 0b10000001
+    8,  // 0b10000010
+    9,  // 0b10000011
+    7,  // 0b10000100
+    8,  // 0b10000101
+    9,  // 0b10000110
+    10, // 0b10000111
+    8,  // 0b10001000
+    9,  // 0b10001001
+    10, // 0b10001010
+    11, // 0b10001011
+    9,  // 0b10001100
+    10, // 0b10001101
+    11, // 0b10001110
+    12, // 0b10001111
+    7,  // 0b10010000
+    8,  // 0b10010001
+    9,  // 0b10010010
+    10, // 0b10010011
+    8,  // 0b10010100
+    9,  // 0b10010101
+    10, // 0b10010110
+    11, // 0b10010111
+    9,  // 0b10011000
+    10, // 0b10011001
+    11, // 0b10011010
+    12, // 0b10011011
+    10, // 0b10011100
+    11, // 0b10011101
+    12, // 0b10011110
+    13, // 0b10011111
+    8,  // 0b10100000
+    9,  // 0b10100001
+    10, // 0b10100010
+    11, // 0b10100011
+    9,  // 0b10100100
+    10, // 0b10100101
+    11, // 0b10100110
+    12, // 0b10100111
+    10, // 0b10101000
+    11, // 0b10101001
+    12, // 0b10101010
+    13, // 0b10101011
+    11, // 0b10101100
+    12, // 0b10101101
+    13, // 0b10101110
+    14, // 0b10101111
+    9,  // 0b10110000
+    10, // 0b10110001
+    11, // 0b10110010
+    12, // 0b10110011
+    10, // 0b10110100
+    11, // 0b10110101
+    12, // 0b10110110
+    13, // 0b10110111
+    11, // 0b10111000
+    12, // 0b10111001
+    13, // 0b10111010
+    14, // 0b10111011
+    12, // 0b10111100
+    13, // 0b10111101
+    14, // 0b10111110
+    15, // 0b10111111
+    7,  // 0b11000000
+    8,  // 0b11000001
+    9,  // 0b11000010
+    10, // 0b11000011
+    8,  // 0b11000100
+    9,  // 0b11000101
+    10, // 0b11000110
+    11, // 0b11000111
+    9,  // 0b11001000
+    10, // 0b11001001
+    11, // 0b11001010
+    12, // 0b11001011
+    10, // 0b11001100
+    11, // 0b11001101
+    12, // 0b11001110
+    13, // 0b11001111
+    8,  // 0b11010000
+    9,  // 0b11010001
+    10, // 0b11010010
+    11, // 0b11010011
+    9,  // 0b11010100
+    10, // 0b11010101
+    11, // 0b11010110
+    12, // 0b11010111
+    10, // 0b11011000
+    11, // 0b11011001
+    12, // 0b11011010
+    13, // 0b11011011
+    11, // 0b11011100
+    12, // 0b11011101
+    13, // 0b11011110
+    14, // 0b11011111
+    9,  // 0b11100000
+    10, // 0b11100001
+    11, // 0b11100010
+    12, // 0b11100011
+    10, // 0b11100100
+    11, // 0b11100101
+    12, // 0b11100110
+    13, // 0b11100111
+    11, // 0b11101000
+    12, // 0b11101001
+    13, // 0b11101010
+    14, // 0b11101011
+    12, // 0b11101100
+    13, // 0b11101101
+    14, // 0b11101110
+    15, // 0b11101111
+    10, // 0b11110000
+    11, // 0b11110001
+    12, // 0b11110010
+    13, // 0b11110011
+    11, // 0b11110100
+    12, // 0b11110101
+    13, // 0b11110110
+    14, // 0b11110111
+    12, // 0b11111000
+    13, // 0b11111001
+    14, // 0b11111010
+    15, // 0b11111011
+    13, // 0b11111100
+    14, // 0b11111101
+    15, // 0b11111110
+    16, // 0b11111111
+];
@@ -0,0 +1,219 @@
+//! Group Varint encoding, which packs four `u32` values behind a single control byte.
+//!
+//! Each group starts with a control byte holding four 2-bit fields, lowest bits first, each
+//! containing the number of bytes used by the corresponding value minus one. The values follow
+//! as 1 to 4 byte little-endian integers. If the number of values is not a multiple of four, the
+//! last group contains only the remaining values, and the unused fields of its control byte are
+//! zero.
+
+#[cfg(all(target_arch = "x86", target_feature = "ssse3"))]
+use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "ssse3"))]
+use core::arch::x86_64::*;
+
+use crate::VarIntDecodeError;
+
+#[cfg(target_feature = "ssse3")]
+pub(crate) mod lookup;
+
+/// Returns the maximum number of bytes needed to encode `count` values.
+#[inline]
+pub const fn max_encoded_len(count: usize) -> usize {
+    count.div_ceil(4) + count * 4
+}
+
+#[inline(always)]
+pub(crate) fn byte_len(num: u32) -> usize {
+    // lzcnt on supported CPUs
+    4 - ((num | 1).leading_zeros() / 8) as usize
+}
+
+/// Encodes a slice of numbers in groups of four, and writes the resulting data to the output
+/// slice. Returns the number of bytes written.
+///
+/// See also: [`max_encoded_len`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+///
+/// # Examples
+/// ```
+/// use varint_simd::group_varint::encode;
+///
+/// let mut out = [0u8; 17];
+/// let len = encode(&[1, 1337, 0, 70000], &mut out);
+/// assert_eq!(&out[..len], &[0b10_00_01_00, 1, 57, 5, 0, 112, 17, 1]);
+/// ```
+#[inline]
+pub fn encode(values: &[u32], out: &mut [u8]) -> usize {
+    let mut pos = 0;
+
+    for group in values.chunks(4) {
+        let control_pos = pos;
+        let mut control = 0u8;
+        pos += 1;
+
+        for (i, num) in group.iter().enumerate() {
+            let len = byte_len(*num);
+            out[pos..pos + len].copy_from_slice(&num.to_le_bytes()[..len]);
+            control |= ((len - 1) as u8) << (i * 2);
+            pos += len;
+        }
+
+        out[control_pos] = control;
+    }
+
+    pos
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode`]. Returns the number
+/// of bytes read.
+///
+/// Groups are decoded with a single shuffle when SSSE3 is available and the input has enough
+/// bytes remaining.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{group_varint::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0u32; 4];
+///     let len = decode(&[0b10_00_01_00, 1, 57, 5, 0, 112, 17, 1], &mut out)?;
+///     assert_eq!(out, [1, 1337, 0, 70000]);
+///     assert_eq!(len, 8);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+    #[allow(unused_mut)]
+    let mut chunks = out.chunks_mut(4);
+
+    #[cfg(target_feature = "ssse3")]
+    for group in chunks.by_ref() {
+        if group.len() < 4 || bytes.len() - pos < 17 {
+            pos += decode_group_slow(&bytes[pos..], group)?;
+            break;
+        }
+
+        // SAFETY: there are at least 17 bytes remaining
+        let (nums, len) = unsafe { decode_group_unsafe(bytes.as_ptr().add(pos)) };
+        group.copy_from_slice(&nums);
+        pos += len as usize;
+    }
+
+    for group in chunks {
+        pos += decode_group_slow(&bytes[pos..], group)?;
+    }
+
+    Ok(pos)
+}
+
+#[inline]
+fn decode_group_slow(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    let control = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+    let mut pos = 1;
+
+    for (i, num) in out.iter_mut().enumerate() {
+        let len = ((control >> (i * 2)) & 0b11) as usize + 1;
+        let data = bytes
+            .get(pos..pos + len)
+            .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+
+        let mut buf = [0u8; 4];
+        buf[..len].copy_from_slice(data);
+        *num = u32::from_le_bytes(buf);
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+/// Decodes a single group of four numbers. Requires SSSE3 support.
+///
+/// Returns a tuple containing the four decoded values, followed by the total number of bytes
+/// read, including the control byte.
+///
+/// # Safety
+/// There must be at least 17 bytes of allocated memory after the start of the pointer. Otherwise,
+/// there may be undefined behavior. Any data after the group is ignored.
+#[inline]
+#[cfg(any(target_feature = "ssse3", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "ssse3")))]
+pub unsafe fn decode_group_unsafe(bytes: *const u8) -> ([u32; 4], u8) {
+    let control = *bytes as usize;
+    let b = _mm_loadu_si128(bytes.add(1) as *const __m128i);
+
+    // Each value is moved into its own 32-bit lane, and the unused bytes are zeroed
+    let shuf = *lookup::LOOKUP_GROUP_VEC.get_unchecked(control);
+    let len = *lookup::LOOKUP_GROUP_LEN.get_unchecked(control);
+
+    let x = _mm_shuffle_epi8(b, shuf);
+
+    (core::mem::transmute::<__m128i, [u32; 4]>(x), len + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, max_encoded_len};
+    use crate::VarIntDecodeError;
+
+    fn check(values: &[u32]) {
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(values, &mut encoded);
+
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        // with padding, so the SIMD path is taken for every full group
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let nums = [
+            0,
+            1,
+            2u32.pow(8) - 1,
+            2u32.pow(8),
+            2u32.pow(16) - 1,
+            2u32.pow(16),
+            2u32.pow(24) - 1,
+            2u32.pow(24),
+            u32::MAX,
+        ];
+
+        // every combination of lengths within a group
+        let mut values = Vec::new();
+        for a in nums {
+            for b in nums {
+                for c in nums {
+                    for d in nums {
+                        values.extend_from_slice(&[a, b, c, d]);
+                    }
+                }
+            }
+        }
+        check(&values);
+
+        for len in 0..8 {
+            check(&values[..len]);
+        }
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut out = [0u32; 4];
+        assert!(matches!(
+            decode(&[], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0b11, 1, 2, 3, 4, 5, 6], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
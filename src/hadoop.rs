@@ -0,0 +1,264 @@
+//! Hadoop `WritableUtils` variable-length integers, as used in SequenceFiles and other Hadoop
+//! file formats.
+//!
+//! Numbers between -112 and 127 are stored in a single byte. Otherwise, the first byte encodes
+//! the sign and the number of bytes that follow: -113 to -120 for 1 to 8 bytes of a positive
+//! number, and -121 to -128 for 1 to 8 bytes of a negative number. The bytes are a big-endian
+//! integer, which is the bitwise complement of the number if it is negative. `VInt` and `VLong`
+//! share the same format, so a `VInt` is at most 5 bytes and a `VLong` is at most 9 bytes.
+
+use crate::VarIntDecodeError;
+
+/// Encodes a number as a `VLong`.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// integer.
+///
+/// # Examples
+/// ```
+/// use varint_simd::hadoop::encode_vlong;
+///
+/// let encoded = encode_vlong(1337);
+/// assert_eq!(encoded, ([0x8E, 5, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 3));
+/// ```
+#[inline]
+pub fn encode_vlong(num: i64) -> ([u8; 16], u8) {
+    let mut res = [0u8; 16];
+
+    if (-112..=127).contains(&num) {
+        res[0] = num as u8;
+        return (res, 1);
+    }
+
+    let (data, marker) = if num < 0 {
+        (!num as u64, -120)
+    } else {
+        (num as u64, -112)
+    };
+    // data is never zero here
+    let data_len = 8 - data.leading_zeros() / 8;
+
+    res[0] = (marker - data_len as i8) as u8;
+    res[1..9].copy_from_slice(&(data << ((8 - data_len) * 8)).to_be_bytes());
+
+    (res, data_len as u8 + 1)
+}
+
+/// Encodes a number as a `VInt`.
+///
+/// See also: [`encode_vlong`]
+#[inline]
+pub fn encode_vint(num: i32) -> ([u8; 16], u8) {
+    encode_vlong(num as i64)
+}
+
+/// Encodes a number as a `VLong`, and writes the resulting data to the slice. Returns the number
+/// of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode_vlong`]
+///
+/// **Panics:** if the slice is too small to contain the integer.
+#[inline]
+pub fn encode_to_slice(num: i64, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode_vlong(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Decodes a single `VLong` from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. For best
+/// performance, provide a slice at least 16 bytes in length, or use the unsafe version directly.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{hadoop::decode_vlong, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode_vlong(&[0x8E, 5, 57])?;
+///     assert_eq!(decoded, (1337, 3));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_vlong(bytes: &[u8]) -> Result<(i64, usize), VarIntDecodeError> {
+    let (num, len) = if bytes.len() >= 16 {
+        unsafe { decode_unsafe(bytes.as_ptr()) }
+    } else if !bytes.is_empty() {
+        let mut data = [0u8; 16];
+        data[..bytes.len()].copy_from_slice(bytes);
+        unsafe { decode_unsafe(data.as_ptr()) }
+    } else {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    };
+
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num, len))
+    }
+}
+
+/// Decodes a single `VInt` from the input slice. Values that do not fit in an `i32` are rejected
+/// with [`VarIntDecodeError::Overflow`].
+///
+/// See also: [`decode_vlong`]
+#[inline]
+pub fn decode_vint(bytes: &[u8]) -> Result<(i32, usize), VarIntDecodeError> {
+    let (num, len) = decode_vlong(bytes)?;
+    let num = i32::try_from(num).map_err(|_| VarIntDecodeError::Overflow)?;
+
+    Ok((num, len))
+}
+
+/// Decodes only the length of a single `VInt` or `VLong` from the input slice.
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{hadoop::decode_len, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(decode_len(&[0x8E])?, 3);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_len(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(first) => Ok(unsafe { decode_len_unsafe(first) }),
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes the length of the next `VInt` or `VLong`
+///
+/// # Safety
+/// The pointer must point to at least one byte of allocated memory.
+#[inline]
+pub unsafe fn decode_len_unsafe(bytes: *const u8) -> usize {
+    match *bytes as i8 {
+        -112..=127 => 1,
+        first @ -120..=-113 => (-111 - first as isize) as usize,
+        first => (-119 - first as isize) as usize,
+    }
+}
+
+/// Decodes a single `VLong` from the input pointer. Returns a tuple containing the decoded number
+/// and the number of bytes read.
+///
+/// # Safety
+/// There must be at least 16 bytes of allocated memory after the beginning of the pointer.
+/// Otherwise, there may be undefined behavior. Any data after the end of the integer are ignored.
+#[inline]
+pub unsafe fn decode_unsafe(bytes: *const u8) -> (i64, usize) {
+    let len = decode_len_unsafe(bytes);
+    let first = *bytes as i8;
+    if len == 1 {
+        return (first as i64, 1);
+    }
+
+    let data_len = len as u32 - 1;
+    let data = u64::from_be(bytes.add(1).cast::<u64>().read_unaligned()) >> ((8 - data_len) * 8);
+
+    if first < -120 {
+        (!data as i64, len)
+    } else {
+        (data as i64, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(value: i64, encoded: &[u8]) {
+        let mut expected = [0u8; 16];
+        expected[..encoded.len()].copy_from_slice(encoded);
+
+        let a = encode_vlong(value);
+        assert_eq!(a.0, expected);
+        assert_eq!(a.1 as usize, encoded.len());
+
+        assert_eq!(decode_vlong(encoded).unwrap(), (value, encoded.len()));
+        assert_eq!(decode_vlong(&expected).unwrap(), (value, encoded.len()));
+        assert_eq!(decode_len(&expected).unwrap(), encoded.len());
+
+        if let Ok(value) = i32::try_from(value) {
+            assert_eq!(encode_vint(value), a);
+            assert_eq!(decode_vint(encoded).unwrap(), (value, encoded.len()));
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        check(0, &[0x00]);
+        check(127, &[0x7F]);
+        check(-1, &[0xFF]);
+        check(-112, &[0x90]);
+        check(128, &[0x8F, 0x80]);
+        check(255, &[0x8F, 0xFF]);
+        check(256, &[0x8E, 0x01, 0x00]);
+        check(-113, &[0x87, 0x70]);
+        check(-256, &[0x87, 0xFF]);
+        check(-257, &[0x86, 0x01, 0x00]);
+        check(i32::MAX as i64, &[0x8C, 0x7F, 0xFF, 0xFF, 0xFF]);
+        check(i32::MIN as i64, &[0x84, 0x7F, 0xFF, 0xFF, 0xFF]);
+        check(
+            i64::MAX,
+            &[0x88, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+        check(
+            i64::MIN,
+            &[0x80, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+
+        let mut data = [0u8; 9];
+        for shift in 0..64 {
+            for value in [
+                1i64 << shift,
+                (1i64 << shift).wrapping_neg(),
+                (1i64 << shift).wrapping_sub(1),
+            ] {
+                let len = encode_to_slice(value, &mut data);
+                assert_eq!(
+                    decode_vlong(&data[..len as usize]).unwrap(),
+                    (value, len as usize)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let (data, len) = encode_vlong(i32::MAX as i64 + 1);
+        assert!(matches!(
+            decode_vint(&data[..len as usize]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        let (data, len) = encode_vlong(i32::MIN as i64 - 1);
+        assert!(matches!(
+            decode_vint(&data[..len as usize]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_vlong(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_vlong(&[0x8E, 0x05]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_vint(&[0x80, 0x7F]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+//! Decoding into and encoding into fixed-capacity [`heapless::Vec`]s, without allocating.
+//!
+//! Instead of panicking when a vector is full, these functions return
+//! [`VarIntDecodeError::CapacityExceeded`] when decoding, or [`CapacityError`] when encoding.
+//!
+//! Requires the `heapless` feature, and works without the `std` feature.
+
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+use heapless::{CapacityError, Vec};
+
+/// Decodes adjacent varints until the end of the input slice, appending them to `out`. Returns
+/// the number of values decoded.
+///
+/// If `out` runs out of space, [`VarIntDecodeError::CapacityExceeded`] is returned. The values
+/// decoded before any error are left in `out`.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{heapless::decode_into, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = heapless::Vec::<u32, 4>::new();
+///     assert_eq!(decode_into(&[0x01, 0xB9, 0x0A], &mut out)?, 2);
+///     assert_eq!(out, [1, 1337]);
+///
+///     let mut small = heapless::Vec::<u32, 1>::new();
+///     assert!(matches!(
+///         decode_into(&[0x01, 0xB9, 0x0A], &mut small),
+///         Err(VarIntDecodeError::CapacityExceeded)
+///     ));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_into<T: VarIntTarget, const N: usize>(
+    bytes: &[u8],
+    out: &mut Vec<T, N>,
+) -> Result<usize, VarIntDecodeError> {
+    crate::decode::decode_packed::<T>(bytes, |num| out.push(num).is_ok())
+}
+
+/// Decodes adjacent varints in ZigZag format until the end of the input slice, appending them to
+/// `out`. Returns the number of values decoded.
+///
+/// See also: [`decode_into`]
+#[inline]
+pub fn decode_zigzag_into<T: SignedVarIntTarget, const N: usize>(
+    bytes: &[u8],
+    out: &mut Vec<T, N>,
+) -> Result<usize, VarIntDecodeError> {
+    crate::decode::decode_packed::<T::Unsigned>(bytes, |num| out.push(num.unzigzag()).is_ok())
+}
+
+/// Encodes a single number to a varint, and appends it to `out`. Returns the number of bytes
+/// written. Requires SSE2 support.
+///
+/// If `out` does not have enough space for the whole varint, nothing is written.
+///
+/// # Examples
+/// ```
+/// use varint_simd::heapless::encode_into;
+///
+/// let mut out = heapless::Vec::<u8, 3>::new();
+/// assert_eq!(encode_into(1337u32, &mut out).unwrap(), 2);
+/// assert_eq!(out, [185, 10]);
+/// assert!(encode_into(1337u32, &mut out).is_err());
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_into<T: VarIntTarget, const N: usize>(
+    num: T,
+    out: &mut Vec<u8, N>,
+) -> Result<u8, CapacityError> {
+    let (data, len) = crate::encode(num);
+    out.extend_from_slice(&data[..len as usize])?;
+
+    Ok(len)
+}
+
+/// Encodes a single number to a varint in ZigZag format, and appends it to `out`. Returns the
+/// number of bytes written.
+///
+/// See also: [`encode_into`]
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_zigzag_into<T: SignedVarIntTarget, const N: usize>(
+    num: T,
+    out: &mut Vec<u8, N>,
+) -> Result<u8, CapacityError> {
+    encode_into(T::Unsigned::zigzag(num), out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let values = [0i64, -1, 1, 300, -300, i64::MIN, i64::MAX];
+
+        let mut encoded = Vec::<u8, 64>::new();
+        let mut len = 0;
+        for num in values {
+            len += encode_zigzag_into(num, &mut encoded).unwrap() as usize;
+        }
+        assert_eq!(encoded.len(), len);
+
+        let mut decoded = Vec::<i64, 7>::new();
+        assert_eq!(decode_zigzag_into(&encoded, &mut decoded).unwrap(), 7);
+        assert_eq!(decoded, values);
+
+        let mut encoded = Vec::<u8, 16>::new();
+        encode_into(u64::MAX, &mut encoded).unwrap();
+        encode_into(5u8, &mut encoded).unwrap();
+        let mut decoded = Vec::<u64, 2>::new();
+        assert_eq!(decode_into(&encoded, &mut decoded).unwrap(), 2);
+        assert_eq!(decoded, [u64::MAX, 5]);
+    }
+
+    #[test]
+    fn capacity() {
+        let mut encoded = Vec::<u8, 11>::new();
+        assert_eq!(encode_into(u64::MAX, &mut encoded).unwrap(), 10);
+        assert!(encode_into(300u16, &mut encoded).is_err());
+        assert_eq!(encoded.len(), 10);
+        assert_eq!(encode_into(1u16, &mut encoded).unwrap(), 1);
+
+        let mut decoded = Vec::<u64, 1>::new();
+        assert!(matches!(
+            decode_into(&encoded, &mut decoded),
+            Err(VarIntDecodeError::CapacityExceeded)
+        ));
+        assert_eq!(decoded, [u64::MAX]);
+
+        let mut decoded = Vec::<u32, 0>::new();
+        assert_eq!(decode_into(&[], &mut decoded).unwrap(), 0);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut decoded = Vec::<u8, 4>::new();
+        assert!(matches!(
+            decode_into(&[0x01, 0x80, 0x02], &mut decoded),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut decoded = Vec::<u32, 4>::new();
+        assert!(matches!(
+            decode_into(&[0x01, 0x80], &mut decoded),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! HPACK and QPACK prefixed integers, as described in
+//! [RFC 7541 Section 5.1](https://www.rfc-editor.org/rfc/rfc7541#section-5.1).
+//!
+//! An integer is stored in the low `N` bits of the first byte if it is small enough, and the high
+//! bits of that byte are left for flags. Otherwise, the `N` bits are all set, and the rest of the
+//! integer follows as an LEB128 varint.
+
+use crate::num::VarIntTarget;
+use crate::VarIntDecodeError;
+
+/// Encodes a single number as an integer with an `N`-bit prefix, where `N` is `prefix_bits`. The
+/// bits of `flags` above the prefix are copied into the first byte.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// integer.
+///
+/// **Panics:** if `prefix_bits` is not between 1 and 8.
+///
+/// # Examples
+/// ```
+/// use varint_simd::hpack::encode_prefixed_int;
+///
+/// let (data, len) = encode_prefixed_int::<u32>(1337, 5, 0);
+/// assert_eq!(&data[..len as usize], &[31, 154, 10]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_prefixed_int<T: VarIntTarget>(num: T, prefix_bits: u8, flags: u8) -> ([u8; 16], u8) {
+    assert!(
+        (1..=8).contains(&prefix_bits),
+        "prefix_bits must be between 1 and 8"
+    );
+
+    let max = (1u64 << prefix_bits) - 1;
+    let flags = (flags as u64 & !max) as u8;
+    let num = num.to_u64();
+
+    let mut res = [0u8; 16];
+    if num < max {
+        res[0] = flags | num as u8;
+        (res, 1)
+    } else {
+        res[0] = flags | max as u8;
+        let (rest, len) = crate::encode(num - max);
+        res[1..].copy_from_slice(&rest[..15]);
+        (res, len + 1)
+    }
+}
+
+/// Decodes a single integer with an `N`-bit prefix from the input slice, where `N` is
+/// `prefix_bits`. The bits of the first byte above the prefix are ignored.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+///
+/// **Panics:** if `prefix_bits` is not between 1 and 8.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{hpack::decode_prefixed_int, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode_prefixed_int::<u32>(&[0b111_11111, 154, 10], 5)?;
+///     assert_eq!(decoded, (1337, 3));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_prefixed_int<T: VarIntTarget>(
+    bytes: &[u8],
+    prefix_bits: u8,
+) -> Result<(T, usize), VarIntDecodeError> {
+    assert!(
+        (1..=8).contains(&prefix_bits),
+        "prefix_bits must be between 1 and 8"
+    );
+
+    let max = (1u64 << prefix_bits) - 1;
+    let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)? as u64 & max;
+
+    let (num, len) = if first < max {
+        (first, 1)
+    } else {
+        let rest = &bytes[1..];
+        let (num, len) = crate::decode::<u64>(rest)?;
+        if len > rest.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        let num = num.checked_add(max).ok_or(VarIntDecodeError::Overflow)?;
+        (num, len + 1)
+    };
+
+    let result = T::cast_u64(num);
+    if result.to_u64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_prefixed_int, encode_prefixed_int};
+    use crate::VarIntDecodeError;
+
+    fn check(value: u64, prefix_bits: u8, encoded: &[u8]) {
+        let (data, len) = encode_prefixed_int(value, prefix_bits, 0);
+        assert_eq!(&data[..len as usize], encoded);
+
+        assert_eq!(
+            decode_prefixed_int::<u64>(encoded, prefix_bits).unwrap(),
+            (value, encoded.len())
+        );
+    }
+
+    // RFC 7541 Appendix C.1
+    #[test]
+    fn rfc7541_examples() {
+        check(10, 5, &[0b00001010]);
+        check(1337, 5, &[0b00011111, 0b10011010, 0b00001010]);
+        check(42, 8, &[0b00101010]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for prefix_bits in 1..=8 {
+            let max = (1u64 << prefix_bits) - 1;
+            check(max - 1, prefix_bits, &[max as u8 - 1]);
+            check(max, prefix_bits, &[max as u8, 0]);
+            check(max + 127, prefix_bits, &[max as u8, 127]);
+            check(max + 128, prefix_bits, &[max as u8, 128, 1]);
+
+            for value in [0, 1000, u32::MAX as u64, u64::MAX] {
+                let (data, len) = encode_prefixed_int(value, prefix_bits, 0);
+                assert_eq!(
+                    decode_prefixed_int::<u64>(&data[..len as usize], prefix_bits).unwrap(),
+                    (value, len as usize)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn flags() {
+        let (data, len) = encode_prefixed_int(1337u32, 5, 0b1010_0000);
+        assert_eq!(&data[..len as usize], &[0b1011_1111, 154, 10]);
+        assert_eq!(
+            decode_prefixed_int::<u32>(&data[..len as usize], 5).unwrap(),
+            (1337, 3)
+        );
+
+        let (data, len) = encode_prefixed_int(3u32, 4, 0xFF);
+        assert_eq!(&data[..len as usize], &[0b1111_0011]);
+    }
+
+    #[test]
+    fn overflow() {
+        // fits in the continuation bytes, but not after adding the prefix
+        let (data, len) = crate::encode(u64::MAX);
+        let mut encoded = vec![0xFF];
+        encoded.extend_from_slice(&data[..len as usize]);
+        assert!(matches!(
+            decode_prefixed_int::<u64>(&encoded, 8),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        let (data, len) = encode_prefixed_int(u16::MAX as u32 + 1, 5, 0);
+        assert!(matches!(
+            decode_prefixed_int::<u16>(&data[..len as usize], 5),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        assert!(matches!(
+            decode_prefixed_int::<u64>(
+                &[0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F],
+                5
+            ),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_prefixed_int::<u32>(&[], 5),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_prefixed_int::<u32>(&[0x1F], 5),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_prefixed_int::<u32>(&[0x1F, 0x80], 5),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,572 @@
+//! Parsing of records in Kafka record batches (message format v2).
+//!
+//! Each record in a batch is laid out as follows, where `varint` and `varlong` are ZigZag encoded
+//! `i32` and `i64` varints:
+//!
+//! ```text
+//! length: varint
+//! attributes: int8
+//! timestampDelta: varlong
+//! offsetDelta: varint
+//! keyLength: varint
+//! key: byte[]
+//! valueLength: varint
+//! value: byte[]
+//! headersCount: varint
+//! headers: [headerKeyLength: varint, headerKey: byte[], headerValueLength: varint, value: byte[]]
+//! ```
+//!
+//! A length of -1 means that the key or value is null. The records of an uncompressed batch
+//! start right after the [`BATCH_HEADER_LEN`] byte batch header.
+//!
+//! See: <https://kafka.apache.org/documentation/#recordbatch>
+
+use crate::num::SignedVarIntTarget;
+#[cfg(target_feature = "ssse3")]
+use crate::num::VarIntTarget;
+use crate::VarIntDecodeError;
+
+/// The length of the fixed-size header at the start of a record batch
+pub const BATCH_HEADER_LEN: usize = 61;
+
+/// A record borrowed from a record batch
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub attributes: u8,
+    pub timestamp_delta: i64,
+    pub offset_delta: i32,
+    pub key: Option<&'a [u8]>,
+    pub value: Option<&'a [u8]>,
+    pub headers: Headers<'a>,
+}
+
+/// A record header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Header<'a> {
+    pub key: &'a [u8],
+    pub value: Option<&'a [u8]>,
+}
+
+/// The headers of a record. These are validated when the record is decoded, and parsed again
+/// when iterated over.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Headers<'a> {
+    data: &'a [u8],
+    count: usize,
+}
+
+impl<'a> Headers<'a> {
+    /// Returns the number of headers
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if there are no headers
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns an iterator over the headers
+    #[inline]
+    pub fn iter(&self) -> HeaderIter<'a> {
+        HeaderIter {
+            data: self.data,
+            pos: 0,
+            remaining: self.count,
+        }
+    }
+}
+
+impl<'a> IntoIterator for Headers<'a> {
+    type Item = Header<'a>;
+    type IntoIter = HeaderIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the headers of a record
+#[derive(Debug, Clone)]
+pub struct HeaderIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for HeaderIter<'a> {
+    type Item = Header<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // The headers were already validated when the record was decoded
+        let header = read_header(self.data, &mut self.pos, self.data.len())
+            .expect("headers should have been validated");
+        Some(header)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for HeaderIter<'_> {}
+
+/// Reads a ZigZag varint at `pos`, which must end before `end`.
+#[inline(always)]
+fn read<T: SignedVarIntTarget>(
+    bytes: &[u8],
+    pos: &mut usize,
+    end: usize,
+) -> Result<T, VarIntDecodeError> {
+    let rest = &bytes[*pos..end];
+    let (num, len) = crate::decode_zigzag::<T>(rest)?;
+    if len > rest.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    *pos += len;
+    Ok(num)
+}
+
+/// Reads a length-prefixed byte array, where a length of -1 means null.
+#[inline(always)]
+fn read_bytes<'a>(
+    bytes: &'a [u8],
+    pos: &mut usize,
+    end: usize,
+) -> Result<Option<&'a [u8]>, VarIntDecodeError> {
+    let len = read::<i32>(bytes, pos, end)?;
+    read_data(bytes, pos, end, len)
+}
+
+/// Reads the data of a byte array whose length has already been read.
+#[inline(always)]
+fn read_data<'a>(
+    bytes: &'a [u8],
+    pos: &mut usize,
+    end: usize,
+    len: i32,
+) -> Result<Option<&'a [u8]>, VarIntDecodeError> {
+    if len == -1 {
+        return Ok(None);
+    }
+
+    let len = usize::try_from(len).map_err(|_| VarIntDecodeError::Invalid)?;
+    if len > end - *pos {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    let data = &bytes[*pos..*pos + len];
+    *pos += len;
+    Ok(Some(data))
+}
+
+#[inline(always)]
+fn read_header<'a>(
+    bytes: &'a [u8],
+    pos: &mut usize,
+    end: usize,
+) -> Result<Header<'a>, VarIntDecodeError> {
+    let key = read_bytes(bytes, pos, end)?.ok_or(VarIntDecodeError::Invalid)?;
+    let value = read_bytes(bytes, pos, end)?;
+    Ok(Header { key, value })
+}
+
+/// The timestamp delta, offset delta, key and value of a record
+type Fields<'a> = (i64, i32, Option<&'a [u8]>, Option<&'a [u8]>);
+
+/// Reads the timestamp and offset deltas, the key and the value.
+///
+/// The timestamp delta, offset delta and key length are adjacent varints, and so is the value
+/// length if the key is null. When SSSE3 is available, they are decoded together as long as they
+/// are short enough.
+#[inline(always)]
+fn read_fields<'a>(
+    bytes: &'a [u8],
+    pos: &mut usize,
+    end: usize,
+) -> Result<Fields<'a>, VarIntDecodeError> {
+    #[cfg(target_feature = "ssse3")]
+    if bytes.len() - *pos >= 16 {
+        // SAFETY: there are at least 16 bytes after pos
+        let (
+            timestamp_delta,
+            offset_delta,
+            key_len,
+            value_len,
+            timestamp_len,
+            offset_len,
+            key_len_len,
+            value_len_len,
+            _,
+        ) = unsafe { crate::decode_four_unsafe::<u32, u16, u16, u32>(bytes.as_ptr().add(*pos)) };
+
+        // Short enough that none of the values can be truncated. Otherwise, let the slower path
+        // below check the values.
+        if timestamp_len < 5 && offset_len < 3 && key_len_len < 3 {
+            let len = (timestamp_len + offset_len + key_len_len) as usize;
+            if len > end - *pos {
+                return Err(VarIntDecodeError::NotEnoughBytes);
+            }
+            *pos += len;
+
+            let key = read_data(bytes, pos, end, (key_len as u32).unzigzag())?;
+            let value = if key.is_none() && value_len_len < 5 {
+                if value_len_len as usize > end - *pos {
+                    return Err(VarIntDecodeError::NotEnoughBytes);
+                }
+                *pos += value_len_len as usize;
+                read_data(bytes, pos, end, value_len.unzigzag())?
+            } else {
+                read_bytes(bytes, pos, end)?
+            };
+
+            return Ok((
+                (timestamp_delta as u64).unzigzag(),
+                (offset_delta as u32).unzigzag(),
+                key,
+                value,
+            ));
+        }
+    }
+
+    let timestamp_delta = read::<i64>(bytes, pos, end)?;
+    let offset_delta = read::<i32>(bytes, pos, end)?;
+    let key = read_bytes(bytes, pos, end)?;
+    let value = read_bytes(bytes, pos, end)?;
+    Ok((timestamp_delta, offset_delta, key, value))
+}
+
+/// Decodes a single record from the input slice.
+///
+/// Produces a tuple containing the record and the number of bytes read. A record whose fields do
+/// not add up to its length is rejected with [`VarIntDecodeError::Invalid`].
+///
+/// # Examples
+/// ```
+/// use varint_simd::{kafka::decode_record, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let data = [22, 0, 0, 0, 1, 10, b'h', b'e', b'l', b'l', b'o', 0];
+///     let (record, len) = decode_record(&data)?;
+///     assert_eq!(record.key, None);
+///     assert_eq!(record.value, Some(&b"hello"[..]));
+///     assert!(record.headers.is_empty());
+///     assert_eq!(len, 12);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_record(bytes: &[u8]) -> Result<(Record<'_>, usize), VarIntDecodeError> {
+    let mut pos = 0;
+    let length = read::<i32>(bytes, &mut pos, bytes.len())?;
+    let length = usize::try_from(length).map_err(|_| VarIntDecodeError::Invalid)?;
+    if length > bytes.len() - pos {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+    let end = pos + length;
+
+    if pos == end {
+        return Err(VarIntDecodeError::Invalid);
+    }
+    let attributes = bytes[pos];
+    pos += 1;
+
+    let (timestamp_delta, offset_delta, key, value) = read_fields(bytes, &mut pos, end)?;
+
+    let count = read::<i32>(bytes, &mut pos, end)?;
+    let count = usize::try_from(count).map_err(|_| VarIntDecodeError::Invalid)?;
+    let headers_start = pos;
+    for _ in 0..count {
+        read_header(bytes, &mut pos, end)?;
+    }
+
+    if pos != end {
+        return Err(VarIntDecodeError::Invalid);
+    }
+
+    Ok((
+        Record {
+            attributes,
+            timestamp_delta,
+            offset_delta,
+            key,
+            value,
+            headers: Headers {
+                data: &bytes[headers_start..end],
+                count,
+            },
+        },
+        end,
+    ))
+}
+
+/// Returns an iterator over the records in the input slice, which should contain the records
+/// section of an uncompressed record batch. Iteration stops at the end of the slice, or after the
+/// first error.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{kafka::records, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let data = [
+///         14, 0, 0, 0, 1, 2, b'a', 0,
+///         14, 0, 2, 2, 1, 2, b'b', 0,
+///     ];
+///     let values = records(&data)
+///         .map(|record| record.map(|record| record.value))
+///         .collect::<Result<Vec<_>, _>>()?;
+///     assert_eq!(values, [Some(&b"a"[..]), Some(&b"b"[..])]);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn records(bytes: &[u8]) -> Records<'_> {
+    Records { bytes, pos: 0 }
+}
+
+/// An iterator over the records in a record batch
+///
+/// See also: [`records`]
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Result<Record<'a>, VarIntDecodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+
+        match decode_record(&self.bytes[self.pos..]) {
+            Ok((record, len)) => {
+                self.pos += len;
+                Some(Ok(record))
+            }
+            Err(err) => {
+                self.pos = self.bytes.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_zigzag;
+
+    fn write_varint<T: SignedVarIntTarget>(out: &mut Vec<u8>, num: T) {
+        let (data, len) = encode_zigzag(num);
+        out.extend_from_slice(&data[..len as usize]);
+    }
+
+    fn write_bytes(out: &mut Vec<u8>, data: Option<&[u8]>) {
+        match data {
+            Some(data) => {
+                write_varint(out, data.len() as i32);
+                out.extend_from_slice(data);
+            }
+            None => write_varint(out, -1i32),
+        }
+    }
+
+    fn encode_record(record: &Record, headers: &[Header]) -> Vec<u8> {
+        let mut body = vec![record.attributes];
+        write_varint(&mut body, record.timestamp_delta);
+        write_varint(&mut body, record.offset_delta);
+        write_bytes(&mut body, record.key);
+        write_bytes(&mut body, record.value);
+        write_varint(&mut body, headers.len() as i32);
+        for header in headers {
+            write_bytes(&mut body, Some(header.key));
+            write_bytes(&mut body, header.value);
+        }
+
+        let mut encoded = vec![];
+        write_varint(&mut encoded, body.len() as i32);
+        encoded.extend_from_slice(&body);
+        encoded
+    }
+
+    fn check(record: &Record, expected: &Record, headers: &[Header]) {
+        assert_eq!(record.attributes, expected.attributes);
+        assert_eq!(record.timestamp_delta, expected.timestamp_delta);
+        assert_eq!(record.offset_delta, expected.offset_delta);
+        assert_eq!(record.key, expected.key);
+        assert_eq!(record.value, expected.value);
+        assert_eq!(record.headers.len(), headers.len());
+        assert!(record.headers.iter().eq(headers.iter().copied()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let long_value = vec![0xAB; 300];
+        let headers = [
+            Header {
+                key: b"content-type",
+                value: Some(b"application/json"),
+            },
+            Header {
+                key: b"trace",
+                value: None,
+            },
+        ];
+
+        let mut batch = vec![];
+        let mut expected = vec![];
+        for (i, (timestamp_delta, offset_delta)) in [
+            (0i64, 0i32),
+            (1, 1),
+            (-1000, 2),
+            (i64::MAX, i32::MAX),
+            (i64::MIN, i32::MIN),
+            (1 << 40, 70000),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let record_headers = if i % 2 == 0 { &headers[..] } else { &[] };
+            let record = Record {
+                attributes: 0,
+                timestamp_delta,
+                offset_delta,
+                key: if i % 3 == 0 { None } else { Some(b"key") },
+                value: if i == 5 {
+                    Some(&long_value)
+                } else {
+                    Some(b"value")
+                },
+                headers: Headers {
+                    data: &[],
+                    count: record_headers.len(),
+                },
+            };
+            batch.extend_from_slice(&encode_record(&record, record_headers));
+            expected.push((record, record_headers));
+        }
+
+        let mut decoded = 0;
+        for (record, (expected, headers)) in records(&batch).zip(&expected) {
+            check(&record.unwrap(), expected, headers);
+            decoded += 1;
+        }
+        assert_eq!(decoded, expected.len());
+
+        // without any data after each record, so the short ones take the slow path
+        for (expected, headers) in &expected {
+            let encoded = encode_record(expected, headers);
+            let (record, len) = decode_record(&encoded).unwrap();
+            check(&record, expected, headers);
+            assert_eq!(len, encoded.len());
+        }
+    }
+
+    #[test]
+    fn roundtrip_lengths() {
+        let long_key = vec![0xCD; 8192];
+        let long_value = vec![0xAB; 20000];
+
+        // Lengths on either side of what the SIMD path decodes, followed by padding
+        for timestamp_delta in [0i64, 1 << 26, 1 << 27, 1 << 40] {
+            for offset_delta in [0i32, 1 << 12, 1 << 13] {
+                for key in [None, Some(&b"key"[..]), Some(&long_key[..])] {
+                    for value in [None, Some(&b"value"[..]), Some(&long_value[..])] {
+                        let record = Record {
+                            attributes: 0,
+                            timestamp_delta,
+                            offset_delta,
+                            key,
+                            value,
+                            headers: Headers {
+                                data: &[],
+                                count: 0,
+                            },
+                        };
+
+                        let mut encoded = encode_record(&record, &[]);
+                        let len = encoded.len();
+                        encoded.resize(len + 16, 0);
+                        let (decoded, decoded_len) = decode_record(&encoded).unwrap();
+                        check(&decoded, &record, &[]);
+                        assert_eq!(decoded_len, len);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        // negative record length
+        assert!(matches!(
+            decode_record(&[1]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        // trailing data after the headers
+        assert!(matches!(
+            decode_record(&[14, 0, 0, 0, 1, 1, 0, 0]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        // key length of -2
+        assert!(matches!(
+            decode_record(&[10, 0, 0, 0, 3, 1, 0]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        // null header key
+        assert!(matches!(
+            decode_record(&[14, 0, 0, 0, 1, 1, 2, 1]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+
+        // offset delta too large for an i32, followed by padding for the fast path
+        let mut data = vec![20, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 1, 1, 0];
+        data.resize(32, 0);
+        assert!(matches!(
+            decode_record(&data),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let data = [22, 0, 0, 0, 1, 10, b'h', b'e', b'l', b'l', b'o', 0];
+        for len in 0..data.len() {
+            assert!(matches!(
+                decode_record(&data[..len]),
+                Err(VarIntDecodeError::NotEnoughBytes)
+            ));
+        }
+
+        // value length past the end of the record, with more data after it
+        let mut data = vec![12, 0, 0, 0, 1, 10, b'h', b'e', b'l', b'l', b'o', 0];
+        data.resize(32, 0);
+        assert!(matches!(
+            decode_record(&data),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+
+        let mut records = records(&[22, 0, 0]);
+        assert!(matches!(
+            records.next(),
+            Some(Err(VarIntDecodeError::NotEnoughBytes))
+        ));
+        assert!(records.next().is_none());
+    }
+}
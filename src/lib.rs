@@ -16,9 +16,47 @@ use core::arch::x86_64::*;
 
 use core::fmt::Debug;
 
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "arrayvec")))]
+pub mod arrayvec;
+#[cfg(feature = "arrow")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "arrow")))]
+pub mod arrow;
+pub mod avro;
+pub mod compact_size;
+pub mod compat;
 pub mod decode;
+pub mod delta;
 pub mod encode;
+pub mod frame_of_reference;
+pub mod group_varint;
+pub mod hadoop;
+#[cfg(feature = "heapless")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "heapless")))]
+pub mod heapless;
+pub mod hpack;
+pub mod kafka;
+pub mod masked_vbyte;
+pub mod memcomparable;
+pub mod minecraft;
+#[cfg(feature = "nom")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "nom")))]
+pub mod nom;
 pub mod num;
+pub mod parquet_rle;
+pub mod prefix_varint;
+#[cfg(feature = "prost")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "prost")))]
+pub mod prost;
+#[cfg(feature = "serde")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "serde")))]
+pub mod serde;
+pub mod sqlite;
+pub mod stream_vbyte;
+pub mod thrift_compact;
+#[cfg(feature = "winnow")]
+#[cfg_attr(rustc_nightly, doc(cfg(feature = "winnow")))]
+pub mod winnow;
 
 #[doc(inline)]
 pub use decode::*;
@@ -38,9 +76,12 @@ fn slice_m256i(n: __m256i) -> [i8; 32] {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum VarIntDecodeError {
     Overflow,
     NotEnoughBytes,
+    Invalid,
+    CapacityExceeded,
 }
 
 impl core::fmt::Display for VarIntDecodeError {
@@ -57,8 +98,8 @@ mod tests {
     #[cfg(target_feature = "avx2")]
     use crate::decode_two_wide_unsafe;
     use crate::{
-        decode, decode_eight_u8_unsafe, decode_four_unsafe, decode_len, decode_two_unsafe, encode,
-        encode_to_slice, VarIntTarget,
+        count_varints, count_varints_checked, decode, decode_eight_u8_unsafe, decode_four_unsafe,
+        decode_len, decode_two_unsafe, encode, encode_to_slice, VarIntDecodeError, VarIntTarget,
     };
 
     use lazy_static::lazy_static;
@@ -78,7 +119,7 @@ mod tests {
 
         let roundtrip: (T, usize) = decode(&expected).unwrap();
         assert_eq!(roundtrip.0, value);
-        assert_eq!(roundtrip.1 as usize, encoded.len());
+        assert_eq!(roundtrip.1, encoded.len());
 
         let len = decode_len::<T>(&expected).unwrap();
         assert_eq!(len, encoded.len());
@@ -827,6 +868,60 @@ mod tests {
         check_decode_8x_u8(&NUMS_U8[..]);
     }
 
+    #[test]
+    fn test_count_varints() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in [0, 1, 15, 16, 17, 31, 32, 33, 100, 1000] {
+            let mut bytes = vec![];
+            for _ in 0..count {
+                let (data, len) = encode(rng.gen::<u64>() >> rng.gen_range(0..64));
+                bytes.extend_from_slice(&data[..len as usize]);
+            }
+
+            assert_eq!(count_varints(&bytes), count);
+            assert_eq!(count_varints_checked::<u64>(&bytes).unwrap(), count);
+
+            bytes.push(0x80);
+            assert_eq!(count_varints(&bytes), count);
+            assert!(matches!(
+                count_varints_checked::<u64>(&bytes),
+                Err(VarIntDecodeError::NotEnoughBytes)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_count_varints_checked_length() {
+        // A varint of every length at every offset, so runs cross chunk boundaries
+        for len in 1..=11 {
+            for offset in 0..70 {
+                let mut bytes = vec![0u8; offset];
+                bytes.extend(core::iter::repeat_n(0x80, len - 1));
+                bytes.push(0x01);
+                bytes.extend_from_slice(&[0u8; 40]);
+
+                let count = offset + 41;
+                assert_eq!(count_varints(&bytes), count);
+
+                let check = |max: usize, result: Result<usize, VarIntDecodeError>| {
+                    if len <= max {
+                        assert_eq!(result.unwrap(), count);
+                    } else {
+                        assert!(matches!(result, Err(VarIntDecodeError::Overflow)));
+                    }
+                };
+                check(2, count_varints_checked::<u8>(&bytes));
+                check(3, count_varints_checked::<u16>(&bytes));
+                check(5, count_varints_checked::<u32>(&bytes));
+                check(10, count_varints_checked::<u64>(&bytes));
+            }
+        }
+    }
+
     // #[test]
     // fn test_two() {
     //     // let result = unsafe { decode_two_unsafe::<u32, u32>([0x80, 0x80, 0x80, 0x80, 0x01, 0x80, 0x80, 0x80, 0x80, 0x01, 0, 0, 0, 0, 0, 0].as_ptr()) };
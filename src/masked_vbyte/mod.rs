@@ -0,0 +1,311 @@
+//! Masked VByte decoding of standard varint streams into `u32` slices.
+//!
+//! The most significant bits of the next 12 bytes are gathered with `_mm_movemask_epi8`, and
+//! the resulting mask is used to look up how many of the upcoming varints can be decoded together
+//! and how to shuffle them into place. Depending on their lengths, six, four, two or one varints
+//! are decoded per step, and runs of single-byte varints are decoded sixteen at a time.
+//!
+//! The input is ordinary protobuf-compatible LEB128 data, as produced by
+//! [`encode`](crate::encode()).
+//!
+//! See: Jeff Plaisance, Nathan Kurz, Daniel Lemire - Vectorized VByte Decoding, International
+//! Symposium on Web Algorithms, 2015: <https://arxiv.org/abs/1503.07387>
+
+#[cfg(all(target_arch = "x86", target_feature = "ssse3"))]
+use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "ssse3"))]
+use core::arch::x86_64::*;
+
+#[cfg(target_feature = "ssse3")]
+use crate::decode::{dual_u32_stage2, lookup as decode_lookup};
+use crate::num::VarIntTarget;
+#[cfg(target_feature = "ssse3")]
+use crate::stream_vbyte::prefix_sum_u32;
+use crate::VarIntDecodeError;
+
+#[cfg(target_feature = "ssse3")]
+mod lookup;
+
+/// Decodes `out.len()` adjacent varints from the input slice. Returns the number of bytes read.
+///
+/// When SSSE3 is available, varints are decoded in blocks while at least 16 bytes of input and
+/// 16 output slots remain, and the rest are decoded one at a time.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{masked_vbyte::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0u32; 3];
+///     let len = decode(&[185, 10, 1, 128, 128, 128, 128, 15], &mut out)?;
+///     assert_eq!(out, [1337, 1, u32::MAX - 0x0fffffff]);
+///     assert_eq!(len, 8);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    decode_inner::<false, false, false>(bytes, out, 0)
+}
+
+/// Decodes `out.len()` adjacent varints, optionally converting each one from ZigZag format and
+/// adding either the previous output value or `base` to it. Returns the number of bytes read.
+///
+/// The transformations are applied to each vector of decoded values before it is stored, so
+/// delta-coded input is summed without a second pass over the output. With `DELTA`, `base` is the
+/// value before the first one.
+#[inline(always)]
+pub(crate) fn decode_inner<const DELTA: bool, const ZIGZAG: bool, const BASE: bool>(
+    bytes: &[u8],
+    out: &mut [u32],
+    base: u32,
+) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+    #[allow(unused_mut)]
+    let mut written = 0;
+
+    #[cfg(target_feature = "ssse3")]
+    {
+        let mut prev = unsafe { _mm_set1_epi32(base as i32) };
+
+        while bytes.len() - pos >= 16 && out.len() - written >= 16 {
+            // SAFETY: there are at least 16 bytes of input and 16 output slots remaining
+            let (count, len) = unsafe {
+                decode_block_unsafe::<DELTA, ZIGZAG, BASE>(
+                    bytes.as_ptr().add(pos),
+                    out.as_mut_ptr().add(written),
+                    &mut prev,
+                )
+            };
+            if count == 0 {
+                // Let the scalar path below report the error
+                break;
+            }
+
+            written += count;
+            pos += len;
+        }
+    }
+
+    let mut prev = if written > 0 { out[written - 1] } else { base };
+    for num in &mut out[written..] {
+        let (decoded, len) = crate::decode::<u32>(&bytes[pos..])?;
+        if pos + len > bytes.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+
+        *num = if ZIGZAG {
+            decoded.unzigzag() as u32
+        } else {
+            decoded
+        };
+        if DELTA {
+            *num = num.wrapping_add(prev);
+            prev = *num;
+        } else if BASE {
+            *num = num.wrapping_add(base);
+        }
+
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+/// Decodes as many varints as possible from the next 16 bytes with a single shuffle. Returns the
+/// number of values written and the number of bytes read, or zero for both if the next varint is
+/// too long for a `u32`.
+///
+/// With `DELTA`, `prev` must hold the previous output value in every lane, and is updated to the
+/// last value written. With `BASE`, `prev` holds the base to add in every lane instead.
+///
+/// # Safety
+/// There must be at least 16 bytes of allocated memory after the start of `bytes`, and room for
+/// 16 values after the start of `out`. Up to 16 values may be written even if fewer are decoded.
+#[inline]
+#[cfg(target_feature = "ssse3")]
+unsafe fn decode_block_unsafe<const DELTA: bool, const ZIGZAG: bool, const BASE: bool>(
+    bytes: *const u8,
+    out: *mut u32,
+    prev: &mut __m128i,
+) -> (usize, usize) {
+    let b = _mm_loadu_si128(bytes as *const __m128i);
+    let bitmask = _mm_movemask_epi8(b) as u32;
+    let zero = _mm_setzero_si128();
+
+    if bitmask == 0 {
+        // Sixteen single-byte varints, so only zero extension is necessary
+        let lo = _mm_unpacklo_epi8(b, zero);
+        let hi = _mm_unpackhi_epi8(b, zero);
+        store::<DELTA, ZIGZAG, BASE>(out, _mm_unpacklo_epi16(lo, zero), prev);
+        store::<DELTA, ZIGZAG, BASE>(out.add(4), _mm_unpackhi_epi16(lo, zero), prev);
+        store::<DELTA, ZIGZAG, BASE>(out.add(8), _mm_unpacklo_epi16(hi, zero), prev);
+        store::<DELTA, ZIGZAG, BASE>(out.add(12), _mm_unpackhi_epi16(hi, zero), prev);
+        return (16, 16);
+    }
+
+    let lookup = *lookup::LOOKUP_MASKED_STEP1.get_unchecked((bitmask & 0b111111111111) as usize);
+    let idx = (lookup & 0b11111111) as usize;
+    let len = ((lookup >> 8) & 0b1111) as usize;
+    let count = ((lookup >> 12) & 0b1111) as usize;
+
+    match lookup >> 16 {
+        0 => {
+            // Six values of up to 2 bytes each, in 16-bit lanes
+            let shuf = *lookup::LOOKUP_MASKED_VEC16.get_unchecked(idx);
+            let comb = _mm_shuffle_epi8(b, shuf);
+
+            let x = _mm_or_si128(
+                _mm_and_si128(comb, _mm_set1_epi16(0x007f)),
+                _mm_srli_epi16(_mm_and_si128(comb, _mm_set1_epi16(0x7f00)), 1),
+            );
+
+            store::<DELTA, ZIGZAG, BASE>(out, _mm_unpacklo_epi16(x, zero), prev);
+            store::<DELTA, ZIGZAG, BASE>(out.add(4), _mm_unpackhi_epi16(x, zero), prev);
+        }
+        1 => {
+            // Four values of up to 3 bytes each, in 32-bit lanes
+            let shuf = *decode_lookup::LOOKUP_QUAD_VEC.get_unchecked(idx);
+            let comb = _mm_shuffle_epi8(b, shuf);
+
+            let x = _mm_or_si128(
+                _mm_or_si128(
+                    _mm_and_si128(comb, _mm_set1_epi32(0x0000007f)),
+                    _mm_srli_epi32(_mm_and_si128(comb, _mm_set1_epi32(0x007f0000)), 2),
+                ),
+                _mm_srli_epi32(_mm_and_si128(comb, _mm_set1_epi32(0x00007f00)), 1),
+            );
+
+            store::<DELTA, ZIGZAG, BASE>(out, x, prev);
+        }
+        2 => {
+            // One or two values of up to 5 bytes each, in 64-bit lanes
+            let shuf = *decode_lookup::LOOKUP_DOUBLE_VEC.get_unchecked(idx);
+            let comb = _mm_shuffle_epi8(b, shuf);
+
+            // The fifth byte of a u32 varint may only contain 4 bits
+            let excess = _mm_and_si128(comb, _mm_set1_epi64x(0x000000f000000000));
+            if _mm_movemask_epi8(_mm_cmpeq_epi8(excess, zero)) != 0xffff {
+                return (0, 0);
+            }
+
+            let x = dual_u32_stage2(comb);
+            let x = _mm_shuffle_epi32(x, 0b00001000);
+
+            store::<DELTA, ZIGZAG, BASE>(out, x, prev);
+        }
+        _ => return (0, 0),
+    }
+
+    if DELTA && !count.is_multiple_of(4) {
+        // The last vector was only partially filled, so its top lane is not the last value
+        *prev = _mm_set1_epi32(*out.add(count - 1) as i32);
+    }
+
+    (count, len)
+}
+
+/// Stores four decoded values, after converting them from ZigZag format and adding them to the
+/// running sum or base in `prev` if requested.
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+unsafe fn store<const DELTA: bool, const ZIGZAG: bool, const BASE: bool>(
+    out: *mut u32,
+    x: __m128i,
+    prev: &mut __m128i,
+) {
+    let mut x = x;
+
+    if ZIGZAG {
+        let sign = _mm_sub_epi32(_mm_setzero_si128(), _mm_and_si128(x, _mm_set1_epi32(1)));
+        x = _mm_xor_si128(_mm_srli_epi32(x, 1), sign);
+    }
+
+    if DELTA {
+        x = prefix_sum_u32(x, *prev);
+        *prev = _mm_shuffle_epi32(x, 0b11111111);
+    } else if BASE {
+        x = _mm_add_epi32(x, *prev);
+    }
+
+    _mm_storeu_si128(out as *mut __m128i, x);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::{encode_to_slice, VarIntDecodeError};
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check(values: &[u32]) {
+        let mut encoded = vec![0u8; values.len() * 5 + 16];
+        let mut len = 0;
+        for num in values {
+            len += encode_to_slice(*num, &mut encoded[len..]) as usize;
+        }
+
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        // with padding, so the SIMD path is taken until the end
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for bits in [7, 8, 14, 15, 21, 22, 28, 29, 32] {
+            let values: Vec<u32> = (0..1000)
+                .map(|_| rng.gen::<u32>() >> (32 - rng.gen_range(1..=bits)))
+                .collect();
+            check(&values);
+        }
+
+        for count in 0..40 {
+            let values: Vec<u32> = (0..count)
+                .map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+                .collect();
+            check(&values);
+        }
+
+        check(&[0; 100]);
+        check(&[u32::MAX; 100]);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut encoded = [0u8; 64];
+        encoded[20..25].copy_from_slice(&[0xff, 0xff, 0xff, 0xff, 0x1f]);
+        let mut out = [0u32; 40];
+        assert!(matches!(
+            decode(&encoded, &mut out),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        let mut encoded = [0u8; 64];
+        encoded[20..26].copy_from_slice(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]);
+        assert!(matches!(
+            decode(&encoded, &mut out),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut out = [0u32; 2];
+        assert!(matches!(
+            decode(&[1], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[1, 0x80], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,396 @@
+//! Order-preserving variable-length integers, for building keys that must sort bytewise in
+//! numeric order.
+//!
+//! The layout matches the ascending varint encoding used by CockroachDB. The first byte is a
+//! marker that determines the length and sign of the encoded integer:
+//!
+//! | First byte | Length | Value                                                             |
+//! |------------|--------|-------------------------------------------------------------------|
+//! | 128 to 135 | 2 to 9 | Negative, the low `136 - A0` bytes of the value in big-endian     |
+//! | 136 to 245 | 1      | `A0 - 136`, for values between 0 and 109                          |
+//! | 246 to 253 | 2 to 9 | Non-negative, the low `A0 - 245` bytes of the value in big-endian |
+//!
+//! Signed and unsigned numbers share the same encoding, so a key containing `5i64` compares
+//! equal to one containing `5u64`. Markers below 128 and above 253 are never produced, which
+//! leaves room for other types in the same key space.
+
+use crate::num::{max_len, SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+const INT_MIN: u8 = 128;
+const INT_ZERO: u8 = 136;
+const INT_SMALL: u64 = 109;
+const INT_MAX: u8 = 253;
+
+/// Writes the low `n` bytes of `num` in big-endian order after the marker byte.
+#[inline(always)]
+fn encode_bytes(marker: u8, num: u64, n: u32) -> ([u8; 16], u8) {
+    let mut res = [0u8; 16];
+    res[0] = marker;
+    res[1..9].copy_from_slice(&(num << ((8 - n) * 8)).to_be_bytes());
+
+    (res, n as u8 + 1)
+}
+
+/// Returns the number of bytes needed to represent `num`, with a minimum of one.
+#[inline(always)]
+fn byte_len(num: u64) -> u32 {
+    8 - (num | 1).leading_zeros() / 8
+}
+
+/// Encodes a single unsigned number to an order-preserving varint.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint.
+///
+/// # Examples
+/// ```
+/// use varint_simd::memcomparable::encode;
+///
+/// let encoded = encode::<u32>(1337);
+/// assert_eq!(encoded, ([247, 5, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 3));
+/// ```
+#[inline]
+pub fn encode<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
+    let num = num.to_u64();
+
+    if num <= INT_SMALL {
+        let mut res = [0u8; 16];
+        res[0] = INT_ZERO + num as u8;
+        (res, 1)
+    } else {
+        let n = byte_len(num);
+        encode_bytes(INT_MAX - 8 + n as u8, num, n)
+    }
+}
+
+/// Encodes a single signed number to an order-preserving varint. Non-negative numbers are
+/// encoded identically to [`encode`].
+///
+/// # Examples
+/// ```
+/// use varint_simd::memcomparable::encode_signed;
+///
+/// let encoded = encode_signed::<i32>(-1);
+/// assert_eq!(encoded, ([135, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 2));
+/// ```
+#[inline]
+pub fn encode_signed<T: SignedVarIntTarget>(num: T) -> ([u8; 16], u8) {
+    let num = num.to_i64();
+
+    if num >= 0 {
+        encode(num as u64)
+    } else {
+        let n = byte_len(num.unsigned_abs());
+        encode_bytes(INT_ZERO - n as u8, num as u64, n)
+    }
+}
+
+/// Encodes a single unsigned number to an order-preserving varint, and writes the resulting data
+/// to the slice. Returns the number of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode`]
+///
+/// **Panics:** if the slice is too small to contain the varint.
+#[inline]
+pub fn encode_to_slice<T: VarIntTarget>(num: T, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Encodes a single signed number to an order-preserving varint, and writes the resulting data
+/// to the slice. Returns the number of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode_signed`]
+///
+/// **Panics:** if the slice is too small to contain the varint.
+#[inline]
+pub fn encode_signed_to_slice<T: SignedVarIntTarget>(num: T, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode_signed(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Decodes only the length of a single order-preserving varint from the input slice.
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+#[inline]
+pub fn decode_len(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(first @ INT_MIN..INT_ZERO) => Ok((INT_ZERO - first) as usize + 1),
+        Some(first @ INT_ZERO..=INT_MAX) => Ok(first.saturating_sub(INT_MAX - 8) as usize + 1),
+        Some(_) => Err(VarIntDecodeError::Invalid),
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes the marker and data of a varint as a sign-extended number. Returns the number, whether
+/// it is negative, and the number of bytes read.
+#[inline(always)]
+fn decode_raw(bytes: &[u8]) -> Result<(u64, bool, usize), VarIntDecodeError> {
+    let len = decode_len(bytes)?;
+    let first = bytes[0];
+
+    if len == 1 {
+        return Ok(((first - INT_ZERO) as u64, false, 1));
+    }
+
+    let data = bytes.get(1..len).ok_or(VarIntDecodeError::NotEnoughBytes)?;
+    let mut buf = [0u8; 8];
+    buf[9 - len..].copy_from_slice(data);
+    let num = u64::from_be_bytes(buf);
+
+    if first < INT_ZERO {
+        // Sign extend
+        let bits = (len as u32 - 1) * 8;
+        Ok((num | !(u64::MAX >> (64 - bits)), true, len))
+    } else {
+        Ok((num, false, len))
+    }
+}
+
+/// Decodes a single unsigned order-preserving varint from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. Negative numbers
+/// produce an overflow error.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{memcomparable::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode::<u32>(&[247, 5, 57])?;
+///     assert_eq!(decoded, (1337, 3));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode<T: VarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    let (num, negative, len) = decode_raw(bytes)?;
+
+    let result = T::cast_u64(num);
+    if negative || len > max_len::<T>() || result.to_u64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+/// Decodes a single signed order-preserving varint from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{memcomparable::decode_signed, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode_signed::<i32>(&[135, 255])?;
+///     assert_eq!(decoded, (-1, 2));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_signed<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    let (num, negative, len) = decode_raw(bytes)?;
+    let num = num as i64;
+
+    let result = T::cast_i64(num);
+    if (!negative && num < 0) || len > max_len::<T::Unsigned>() || result.to_i64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+/// Decodes `out.len()` adjacent unsigned order-preserving varints from the input slice. Returns
+/// the number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{memcomparable::decode_slice, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0u32; 2];
+///     let len = decode_slice(&[136, 247, 5, 57], &mut out)?;
+///     assert_eq!(out, [0, 1337]);
+///     assert_eq!(len, 4);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_slice<T: VarIntTarget>(
+    bytes: &[u8],
+    out: &mut [T],
+) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+    for num in out {
+        let (decoded, len) = decode(&bytes[pos..])?;
+        *num = decoded;
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+/// Decodes `out.len()` adjacent signed order-preserving varints from the input slice. Returns
+/// the number of bytes read.
+///
+/// See also: [`decode_slice`]
+#[inline]
+pub fn decode_slice_signed<T: SignedVarIntTarget>(
+    bytes: &[u8],
+    out: &mut [T],
+) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+    for num in out {
+        let (decoded, len) = decode_signed(&bytes[pos..])?;
+        *num = decoded;
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode, decode_len, decode_signed, decode_slice, decode_slice_signed, encode,
+        encode_signed, encode_signed_to_slice, encode_to_slice,
+    };
+    use crate::VarIntDecodeError;
+
+    fn to_vec(encoded: ([u8; 16], u8)) -> Vec<u8> {
+        encoded.0[..encoded.1 as usize].to_vec()
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_eq!(to_vec(encode(0u8)), [136]);
+        assert_eq!(to_vec(encode(109u8)), [245]);
+        assert_eq!(to_vec(encode(110u8)), [246, 110]);
+        assert_eq!(to_vec(encode(u8::MAX)), [246, 255]);
+        assert_eq!(to_vec(encode(256u16)), [247, 1, 0]);
+        assert_eq!(
+            to_vec(encode(u64::MAX)),
+            [253, 255, 255, 255, 255, 255, 255, 255, 255]
+        );
+
+        assert_eq!(to_vec(encode_signed(-1i8)), [135, 255]);
+        assert_eq!(to_vec(encode_signed(-255i16)), [135, 1]);
+        assert_eq!(to_vec(encode_signed(-256i16)), [134, 255, 0]);
+        assert_eq!(
+            to_vec(encode_signed(i64::MIN)),
+            [128, 128, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(encode_signed(42i32), encode(42u32));
+
+        for shift in 0..64 {
+            for num in [(1u64 << shift) - 1, 1u64 << shift, (1u64 << shift) + 1] {
+                let encoded = to_vec(encode(num));
+                assert_eq!(decode::<u64>(&encoded).unwrap(), (num, encoded.len()));
+                assert_eq!(decode_len(&encoded).unwrap(), encoded.len());
+
+                for num in [num as i64, (num as i64).wrapping_neg()] {
+                    let encoded = to_vec(encode_signed(num));
+                    assert_eq!(
+                        decode_signed::<i64>(&encoded).unwrap(),
+                        (num, encoded.len())
+                    );
+                    assert_eq!(decode_len(&encoded).unwrap(), encoded.len());
+                }
+            }
+        }
+
+        for num in i16::MIN..=i16::MAX {
+            let encoded = to_vec(encode_signed(num));
+            assert_eq!(
+                decode_signed::<i16>(&encoded).unwrap(),
+                (num, encoded.len())
+            );
+        }
+    }
+
+    #[test]
+    fn sorts_bytewise() {
+        let mut values = vec![i64::MIN, i64::MAX, 0];
+        for shift in 0..63 {
+            for num in [(1i64 << shift) - 1, 1i64 << shift, (1i64 << shift) + 1] {
+                values.push(num);
+                values.push(-num);
+            }
+        }
+        values.sort_unstable();
+        values.dedup();
+
+        for window in values.windows(2) {
+            let a = to_vec(encode_signed(window[0]));
+            let b = to_vec(encode_signed(window[1]));
+            assert!(a < b, "{} {}", window[0], window[1]);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(
+            decode::<u32>(&to_vec(encode_signed(-1i64))),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode::<u8>(&to_vec(encode(256u16))),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_signed::<i8>(&to_vec(encode_signed(-129i16))),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_signed::<i64>(&to_vec(encode(u64::MAX))),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        for first in [0, 127, 254, 255] {
+            assert!(matches!(
+                decode::<u64>(&[first, 0, 0]),
+                Err(VarIntDecodeError::Invalid)
+            ));
+        }
+        assert!(matches!(
+            decode::<u64>(&[247, 1]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn bulk() {
+        let values = [0i32, -1, 1, i32::MIN, i32::MAX, 1000, -1000];
+        let mut encoded = [0u8; 64];
+        let mut len = 0;
+        for num in values {
+            len += encode_signed_to_slice(num, &mut encoded[len..]) as usize;
+        }
+
+        let mut decoded = [0i32; 7];
+        assert_eq!(
+            decode_slice_signed(&encoded[..len], &mut decoded).unwrap(),
+            len
+        );
+        assert_eq!(decoded, values);
+
+        let values = [0u16, 1, 110, u16::MAX];
+        let mut len = 0;
+        for num in values {
+            len += encode_to_slice(num, &mut encoded[len..]) as usize;
+        }
+
+        let mut decoded = [0u16; 4];
+        assert_eq!(decode_slice(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+    }
+}
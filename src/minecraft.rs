@@ -0,0 +1,257 @@
+//! Minecraft protocol VarInt and VarLong values.
+//!
+//! These are signed integers stored as ordinary varints of their two's complement bits, without
+//! ZigZag encoding. A VarInt is at most 5 bytes and a VarLong is at most 10 bytes, so negative
+//! numbers always take the maximum length: `-1` is `[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]`. Unlike
+//! protobuf `int32` fields, negative VarInts are not sign extended to 10 bytes.
+//!
+//! See: <https://wiki.vg/Protocol#VarInt_and_VarLong>
+
+use crate::VarIntDecodeError;
+
+/// Encodes an `i32` as a VarInt.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint.
+///
+/// # Examples
+/// ```
+/// use varint_simd::minecraft::encode_varint_i32_twos_complement;
+///
+/// let (data, len) = encode_varint_i32_twos_complement(-1);
+/// assert_eq!(&data[..len as usize], &[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_varint_i32_twos_complement(num: i32) -> ([u8; 16], u8) {
+    crate::encode(num as u32)
+}
+
+/// Encodes an `i64` as a VarLong.
+///
+/// See also: [`encode_varint_i32_twos_complement`]
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_varint_i64_twos_complement(num: i64) -> ([u8; 16], u8) {
+    crate::encode(num as u64)
+}
+
+/// Decodes a VarInt from the input slice. VarInts longer than 5 bytes, or with more than 32 bits,
+/// are rejected with [`VarIntDecodeError::Overflow`].
+///
+/// Produces a tuple containing the decoded number and the number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{minecraft::decode_varint_i32_twos_complement, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode_varint_i32_twos_complement(&[0x80, 0x80, 0x80, 0x80, 0x08])?;
+///     assert_eq!(decoded, (i32::MIN, 5));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_varint_i32_twos_complement(bytes: &[u8]) -> Result<(i32, usize), VarIntDecodeError> {
+    let (num, len) = crate::decode::<u32>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num as i32, len))
+    }
+}
+
+/// Decodes a VarLong from the input slice. VarLongs longer than 10 bytes, or with more than 64
+/// bits, are rejected with [`VarIntDecodeError::Overflow`].
+///
+/// See also: [`decode_varint_i32_twos_complement`]
+#[inline]
+pub fn decode_varint_i64_twos_complement(bytes: &[u8]) -> Result<(i64, usize), VarIntDecodeError> {
+    let (num, len) = crate::decode::<u64>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num as i64, len))
+    }
+}
+
+/// Decodes `out.len()` adjacent VarInts from the input slice, such as the fields of a packet.
+/// Returns the number of bytes read.
+///
+/// The values are decoded in blocks with [`masked_vbyte::decode`](crate::masked_vbyte::decode).
+///
+/// # Examples
+/// ```
+/// use varint_simd::{minecraft::decode_batch_i32, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0i32; 3];
+///     let len = decode_batch_i32(&[0xDD, 0xC7, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00], &mut out)?;
+///     assert_eq!(out, [25565, -1, 0]);
+///     assert_eq!(len, 9);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_batch_i32(bytes: &[u8], out: &mut [i32]) -> Result<usize, VarIntDecodeError> {
+    // SAFETY: i32 and u32 have the same size and alignment, and every bit pattern is valid for
+    // both
+    let out = unsafe { core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut u32, out.len()) };
+    crate::masked_vbyte::decode(bytes, out)
+}
+
+/// Decodes `out.len()` adjacent VarLongs from the input slice. Returns the number of bytes read.
+///
+/// See also: [`decode_batch_i32`]
+#[inline]
+pub fn decode_batch_i64(bytes: &[u8], out: &mut [i64]) -> Result<usize, VarIntDecodeError> {
+    let mut pos = 0;
+
+    for num in out {
+        let (decoded, len) = decode_varint_i64_twos_complement(&bytes[pos..])?;
+        *num = decoded;
+        pos += len;
+    }
+
+    Ok(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn varints() {
+        // examples from the protocol documentation
+        for (num, encoded) in [
+            (0, &[0x00][..]),
+            (1, &[0x01]),
+            (127, &[0x7F]),
+            (128, &[0x80, 0x01]),
+            (255, &[0xFF, 0x01]),
+            (25565, &[0xDD, 0xC7, 0x01]),
+            (2097151, &[0xFF, 0xFF, 0x7F]),
+            (2147483647, &[0xFF, 0xFF, 0xFF, 0xFF, 0x07]),
+            (-1, &[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]),
+            (-2147483648, &[0x80, 0x80, 0x80, 0x80, 0x08]),
+        ] {
+            let (data, len) = encode_varint_i32_twos_complement(num);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(
+                decode_varint_i32_twos_complement(encoded).unwrap(),
+                (num, encoded.len())
+            );
+        }
+    }
+
+    #[test]
+    fn varlongs() {
+        for (num, encoded) in [
+            (0, &[0x00][..]),
+            (2147483647, &[0xFF, 0xFF, 0xFF, 0xFF, 0x07]),
+            (
+                9223372036854775807,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F],
+            ),
+            (
+                -1,
+                &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+            (
+                -2147483648,
+                &[0x80, 0x80, 0x80, 0x80, 0xF8, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+            (
+                -9223372036854775808,
+                &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+            ),
+        ] {
+            let (data, len) = encode_varint_i64_twos_complement(num);
+            assert_eq!(&data[..len as usize], encoded);
+            assert_eq!(
+                decode_varint_i64_twos_complement(encoded).unwrap(),
+                (num, encoded.len())
+            );
+        }
+    }
+
+    #[test]
+    fn batches() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in [0, 1, 15, 16, 17, 100, 1000] {
+            let values: Vec<i64> = (0..count)
+                .map(|_| rng.gen::<i64>() >> rng.gen_range(0..64))
+                .collect();
+
+            let mut encoded = vec![];
+            let mut encoded_long = vec![];
+            for num in &values {
+                let (data, len) = encode_varint_i32_twos_complement(*num as i32);
+                encoded.extend_from_slice(&data[..len as usize]);
+                let (data, len) = encode_varint_i64_twos_complement(*num);
+                encoded_long.extend_from_slice(&data[..len as usize]);
+            }
+
+            let mut decoded = vec![0i32; values.len()];
+            assert_eq!(
+                decode_batch_i32(&encoded, &mut decoded).unwrap(),
+                encoded.len()
+            );
+            assert!(decoded.iter().zip(&values).all(|(a, b)| *a == *b as i32));
+
+            let mut decoded = vec![0i64; values.len()];
+            assert_eq!(
+                decode_batch_i64(&encoded_long, &mut decoded).unwrap(),
+                encoded_long.len()
+            );
+            assert_eq!(decoded, values);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        // 6 bytes, and 33 bits
+        for encoded in [
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x00][..],
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0x1F],
+        ] {
+            assert!(matches!(
+                decode_varint_i32_twos_complement(encoded),
+                Err(VarIntDecodeError::Overflow)
+            ));
+            assert!(matches!(
+                decode_batch_i32(encoded, &mut [0]),
+                Err(VarIntDecodeError::Overflow)
+            ));
+        }
+
+        assert!(matches!(
+            decode_varint_i64_twos_complement(&[
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x03
+            ]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_varint_i32_twos_complement(&[0xFF, 0xFF]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_varint_i64_twos_complement(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_batch_i64(&[0x01, 0x80], &mut [0, 0]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+//! [nom](https://docs.rs/nom) parsers for varints.
+//!
+//! These are streaming parsers: if the input ends in the middle of a varint, they return
+//! [`Err::Incomplete`] with the number of additional bytes needed. Wrap them in
+//! [`nom::combinator::complete`](::nom::combinator::complete) to parse complete input.
+//!
+//! Decoding errors such as [`VarIntDecodeError::Overflow`] are reported as [`Err::Error`] through
+//! [`FromExternalError`], with [`ErrorKind::TooLarge`].
+//!
+//! Requires the `nom` feature.
+//!
+//! # Examples
+//! ```
+//! use nom::{error::Error, IResult, Parser};
+//! use varint_simd::nom::{length_prefixed, varint, zigzag};
+//!
+//! fn header(input: &[u8]) -> IResult<&[u8], (u32, i64, &[u8])> {
+//!     (varint, zigzag, length_prefixed).parse(input)
+//! }
+//!
+//! let (rest, parsed) = header(&[185, 10, 3, 2, b'h', b'i', 0xFF]).unwrap();
+//! assert_eq!(parsed, (1337, -2, &b"hi"[..]));
+//! assert_eq!(rest, &[0xFF]);
+//!
+//! assert_eq!(
+//!     varint::<u64, Error<&[u8]>>(&[0x80, 0x80]),
+//!     Err(nom::Err::Incomplete(nom::Needed::new(1)))
+//! );
+//! ```
+
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+use ::nom::error::{ErrorKind, FromExternalError, ParseError};
+use ::nom::{Err, IResult, Needed};
+
+/// Parses a single varint.
+#[inline]
+pub fn varint<'a, T, E>(input: &'a [u8]) -> IResult<&'a [u8], T, E>
+where
+    T: VarIntTarget,
+    E: ParseError<&'a [u8]> + FromExternalError<&'a [u8], VarIntDecodeError>,
+{
+    match crate::decode::<T>(input) {
+        Ok((num, len)) if len <= input.len() => Ok((&input[len..], num)),
+        Ok((_, len)) => Err(Err::Incomplete(Needed::new(len - input.len()))),
+        Err(VarIntDecodeError::NotEnoughBytes) => Err(Err::Incomplete(Needed::new(1))),
+        Err(err) => Err(Err::Error(E::from_external_error(
+            input,
+            ErrorKind::TooLarge,
+            err,
+        ))),
+    }
+}
+
+/// Parses a single varint in ZigZag format.
+///
+/// See also: [`varint`]
+#[inline]
+pub fn zigzag<'a, T, E>(input: &'a [u8]) -> IResult<&'a [u8], T, E>
+where
+    T: SignedVarIntTarget,
+    E: ParseError<&'a [u8]> + FromExternalError<&'a [u8], VarIntDecodeError>,
+{
+    varint::<T::Unsigned, E>(input).map(|(rest, num)| (rest, num.unzigzag()))
+}
+
+/// Parses a varint length prefix as a `usize`, such as for use with
+/// [`nom::multi::length_count`](::nom::multi::length_count). Lengths that do not fit in a `usize`
+/// are rejected with [`VarIntDecodeError::Overflow`].
+#[inline]
+pub fn varint_len<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], usize, E>
+where
+    E: ParseError<&'a [u8]> + FromExternalError<&'a [u8], VarIntDecodeError>,
+{
+    let (rest, len) = varint::<u64, E>(input)?;
+    match usize::try_from(len) {
+        Ok(len) => Ok((rest, len)),
+        Err(_) => Err(Err::Error(E::from_external_error(
+            input,
+            ErrorKind::TooLarge,
+            VarIntDecodeError::Overflow,
+        ))),
+    }
+}
+
+/// Parses a slice prefixed with its length as a varint.
+///
+/// See also: [`varint_len`]
+#[inline]
+pub fn length_prefixed<'a, E>(input: &'a [u8]) -> IResult<&'a [u8], &'a [u8], E>
+where
+    E: ParseError<&'a [u8]> + FromExternalError<&'a [u8], VarIntDecodeError>,
+{
+    let (rest, len) = varint_len::<E>(input)?;
+    if len > rest.len() {
+        return Err(Err::Incomplete(Needed::new(len - rest.len())));
+    }
+
+    let (data, rest) = rest.split_at(len);
+    Ok((rest, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::nom::combinator::complete;
+    use ::nom::error::Error;
+    use ::nom::Parser;
+
+    type Result<'a, T> = IResult<&'a [u8], T, Error<&'a [u8]>>;
+
+    #[test]
+    fn varints() {
+        for value in [0u64, 1, 127, 128, 16383, 16384, u32::MAX as u64, u64::MAX] {
+            let (data, len) = crate::encode(value);
+            let len = len as usize;
+
+            let res: Result<u64> = varint(&data[..len + 1]);
+            assert_eq!(res, Ok((&data[len..len + 1], value)));
+
+            for end in 0..len {
+                let res: Result<u64> = varint(&data[..end]);
+                assert_eq!(res, Err(Err::Incomplete(Needed::new(1))));
+            }
+        }
+
+        let res: Result<i32> = zigzag(&[0x03]);
+        assert_eq!(res, Ok((&[][..], -2)));
+        let res: Result<i8> = zigzag(&[0xFF, 0x01]);
+        assert_eq!(res, Ok((&[][..], i8::MIN)));
+    }
+
+    #[test]
+    fn length_prefixes() {
+        let input = [3, b'a', b'b', b'c', 7];
+        let res: Result<&[u8]> = length_prefixed(&input);
+        assert_eq!(res, Ok((&[7][..], &b"abc"[..])));
+
+        let res: Result<&[u8]> = length_prefixed(&input[..2]);
+        assert_eq!(res, Err(Err::Incomplete(Needed::new(2))));
+
+        let res: Result<Vec<u16>> =
+            ::nom::multi::length_count(varint_len, varint).parse(&[2, 0x80, 0x01, 0x05]);
+        assert_eq!(res, Ok((&[][..], vec![128, 5])));
+    }
+
+    #[test]
+    fn errors() {
+        let input = [0xFF, 0xFF, 0xFF, 0xFF, 0x1F];
+        let res: Result<u32> = varint(&input);
+        assert_eq!(
+            res,
+            Err(Err::Error(Error::new(&input[..], ErrorKind::TooLarge)))
+        );
+
+        let res: Result<u32> = complete(varint).parse(&[0x80]);
+        assert!(matches!(res, Err(Err::Error(_))));
+    }
+}
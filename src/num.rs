@@ -32,6 +32,9 @@ pub trait VarIntTarget: Debug + Eq + PartialEq + PartialOrd + Sized + Copy {
     /// Cast from u64 to self
     fn cast_u64(num: u64) -> Self;
 
+    /// Cast from self to u64
+    fn to_u64(self) -> u64;
+
     /// Splits this number into 7-bit segments for encoding
     fn num_to_scalar_stage1(self) -> u64;
 
@@ -45,6 +48,25 @@ pub trait VarIntTarget: Debug + Eq + PartialEq + PartialOrd + Sized + Copy {
     fn unzigzag(self) -> Self::Signed;
 }
 
+/// The maximum length of varint that is necessary to represent a number of type `T` in formats
+/// that need at most 9 bytes for a 64-bit value, such as prefix, SQLite4 and order-preserving
+/// varints
+#[inline(always)]
+pub(crate) const fn max_len<T: VarIntTarget>() -> usize {
+    if T::MAX_VARINT_BYTES > 9 {
+        9
+    } else {
+        T::MAX_VARINT_BYTES as usize
+    }
+}
+
+/// Returns the number of bytes needed to encode the value as a varint
+#[inline(always)]
+pub(crate) fn encoded_len<T: VarIntTarget>(num: T) -> usize {
+    // 9 / 64 is an approximation of 1 / 7 that is exact for every bit length
+    ((((num.to_u64() | 1).leading_zeros() ^ 63) * 9 + 73) / 64) as usize
+}
+
 impl VarIntTarget for u8 {
     type Signed = i8;
     const MAX_VARINT_BYTES: u8 = 2;
@@ -80,6 +102,11 @@ impl VarIntTarget for u8 {
         num as u8
     }
 
+    #[inline(always)]
+    fn to_u64(self) -> u64 {
+        self as u64
+    }
+
     #[inline(always)]
     #[cfg(all(target_arch = "x86_64", target_feature = "bmi2", fast_pdep))]
     fn num_to_scalar_stage1(self) -> u64 {
@@ -163,6 +190,11 @@ impl VarIntTarget for u16 {
         num as u16
     }
 
+    #[inline(always)]
+    fn to_u64(self) -> u64 {
+        self as u64
+    }
+
     #[inline(always)]
     #[cfg(all(target_arch = "x86_64", target_feature = "bmi2", fast_pdep))]
     fn num_to_scalar_stage1(self) -> u64 {
@@ -247,6 +279,11 @@ impl VarIntTarget for u32 {
         num as u32
     }
 
+    #[inline(always)]
+    fn to_u64(self) -> u64 {
+        self as u64
+    }
+
     #[inline(always)]
     #[cfg(all(target_arch = "x86_64", target_feature = "bmi2", fast_pdep))]
     fn num_to_scalar_stage1(self) -> u64 {
@@ -335,7 +372,7 @@ impl VarIntTarget for u64 {
     #[cfg(all(target_feature = "avx2", not(all(target_feature = "bmi2", fast_pdep))))]
     fn vector_to_num(res: [u8; 16]) -> Self {
         let pt1 = unsafe {
-            let b = core::mem::transmute(res);
+            let b = core::mem::transmute::<[u8; 16], __m128i>(res);
 
             let c = _mm_broadcastq_epi64(b);
             let d = _mm_or_si128(
@@ -470,6 +507,11 @@ impl VarIntTarget for u64 {
         num
     }
 
+    #[inline(always)]
+    fn to_u64(self) -> u64 {
+        self
+    }
+
     #[inline(always)]
     fn zigzag(from: Self::Signed) -> Self {
         ((from << 1) ^ (from >> 63)) as Self
@@ -497,20 +539,66 @@ pub trait SignedVarIntTarget: Debug + Eq + PartialEq + Sized + Copy {
     fn unzigzag(from: Self::Unsigned) -> Self {
         Self::Unsigned::unzigzag(from)
     }
+
+    /// Cast from i64 to self
+    fn cast_i64(num: i64) -> Self;
+
+    /// Cast from self to i64
+    fn to_i64(self) -> i64;
 }
 
 impl SignedVarIntTarget for i8 {
     type Unsigned = u8;
+
+    #[inline(always)]
+    fn cast_i64(num: i64) -> Self {
+        num as i8
+    }
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
 }
 
 impl SignedVarIntTarget for i16 {
     type Unsigned = u16;
+
+    #[inline(always)]
+    fn cast_i64(num: i64) -> Self {
+        num as i16
+    }
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
 }
 
 impl SignedVarIntTarget for i32 {
     type Unsigned = u32;
+
+    #[inline(always)]
+    fn cast_i64(num: i64) -> Self {
+        num as i32
+    }
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
 }
 
 impl SignedVarIntTarget for i64 {
     type Unsigned = u64;
+
+    #[inline(always)]
+    fn cast_i64(num: i64) -> Self {
+        num
+    }
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        self
+    }
 }
@@ -0,0 +1,333 @@
+//! Decoding of the Parquet RLE/bit-packing hybrid encoding into `u32` slices.
+//!
+//! The stream is a series of runs, each starting with a ULEB128 header. If the lowest bit of the
+//! header is zero, the run is a repeated value: the rest of the header is the number of
+//! repetitions, and the value follows as a little-endian integer of `ceil(bit_width / 8)` bytes.
+//! Otherwise, the rest of the header is a number of groups of eight values, and each group is
+//! bit-packed into `bit_width` bytes, lowest bits first.
+//!
+//! This encoding is used for definition levels, repetition levels, and dictionary indices.
+//!
+//! See: <https://parquet.apache.org/docs/file-format/data-pages/encodings/>
+
+#[cfg(all(target_arch = "x86", target_feature = "avx2"))]
+use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+use core::arch::x86_64::*;
+
+use crate::VarIntDecodeError;
+
+/// Decodes `out.len()` values of `bit_width` bits each from the input slice. Returns the number of
+/// bytes read.
+///
+/// Bit-packed runs always hold a multiple of eight values, so the last run may contain more values
+/// than requested. Its extra values are discarded, but the whole run is counted as read.
+///
+/// When AVX2 is available, bit-packed runs with a bit width of up to 25 are unpacked eight values
+/// at a time.
+///
+/// **Panics:** if `bit_width` is greater than 32.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{parquet_rle::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0u32; 11];
+///     // 3 repetitions of 4, then one bit-packed group of 0 to 7
+///     let data = [0x06, 0x04, 0x03, 0x88, 0xC6, 0xFA];
+///     assert_eq!(decode(&data, 3, &mut out)?, 6);
+///     assert_eq!(out, [4, 4, 4, 0, 1, 2, 3, 4, 5, 6, 7]);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode(bytes: &[u8], bit_width: u8, out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    assert!(bit_width <= 32, "bit_width must be at most 32");
+
+    let bit_width = bit_width as usize;
+    let value_len = bit_width.div_ceil(8);
+    let mask = mask(bit_width);
+
+    let mut pos = 0;
+    let mut written = 0;
+
+    while written < out.len() {
+        let rest = &bytes[pos..];
+        let (header, len) = crate::decode::<u32>(rest)?;
+        if len > rest.len() {
+            return Err(VarIntDecodeError::NotEnoughBytes);
+        }
+        pos += len;
+
+        let count = (header >> 1) as usize;
+        let remaining = out.len() - written;
+
+        if header & 1 == 0 {
+            let data = bytes
+                .get(pos..pos + value_len)
+                .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+            let value = data
+                .iter()
+                .rev()
+                .fold(0u32, |value, byte| value << 8 | *byte as u32);
+            if value & !mask != 0 {
+                return Err(VarIntDecodeError::Invalid);
+            }
+            pos += value_len;
+
+            let count = count.min(remaining);
+            out[written..written + count].fill(value);
+            written += count;
+        } else {
+            let run_len = count
+                .checked_mul(bit_width)
+                .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+            let run = pos
+                .checked_add(run_len)
+                .and_then(|end| bytes.get(pos..end))
+                .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+            pos += run_len;
+
+            let count = count.saturating_mul(8).min(remaining);
+            unpack(run, bit_width, &mut out[written..written + count]);
+            written += count;
+        }
+    }
+
+    Ok(pos)
+}
+
+#[inline(always)]
+fn mask(bit_width: usize) -> u32 {
+    if bit_width == 32 {
+        u32::MAX
+    } else {
+        (1 << bit_width) - 1
+    }
+}
+
+/// Unpacks `out.len()` values from a bit-packed run. The run must contain at least that many
+/// values.
+#[inline]
+fn unpack(run: &[u8], bit_width: usize, out: &mut [u32]) {
+    if bit_width == 0 {
+        out.fill(0);
+        return;
+    }
+
+    #[cfg(target_feature = "avx2")]
+    if bit_width <= 25 {
+        unpack_avx2(run, bit_width, out);
+        return;
+    }
+
+    unpack_scalar(run, bit_width, out);
+}
+
+#[inline]
+fn unpack_scalar(run: &[u8], bit_width: usize, out: &mut [u32]) {
+    let mask = mask(bit_width) as u64;
+
+    for (i, num) in out.iter_mut().enumerate() {
+        let bit = i * bit_width;
+        let start = bit / 8;
+        let end = (start + 8).min(run.len());
+
+        let mut data = [0u8; 8];
+        data[..end - start].copy_from_slice(&run[start..end]);
+        *num = ((u64::from_le_bytes(data) >> (bit % 8)) & mask) as u32;
+    }
+}
+
+/// Unpacks groups of eight values with two shuffles and variable shifts. Every value of up to 25
+/// bits fits in the four bytes starting at its first byte, so each value can be shuffled into a
+/// 32-bit lane and shifted into place.
+#[inline]
+#[cfg(target_feature = "avx2")]
+fn unpack_avx2(run: &[u8], bit_width: usize, out: &mut [u32]) {
+    // The shuffles and shifts only depend on the bit width, so compute them once per run. The
+    // second half of each group is loaded from the byte containing its first bit.
+    let mut shuf = [[0u8; 16]; 2];
+    let mut shift = [[0u32; 4]; 2];
+    let mut offset = [0usize; 2];
+
+    for half in 0..2 {
+        offset[half] = half * 4 * bit_width / 8;
+        for lane in 0..4 {
+            let bit = (half * 4 + lane) * bit_width - offset[half] * 8;
+            for byte in 0..4 {
+                shuf[half][lane * 4 + byte] = (bit / 8 + byte) as u8;
+            }
+            shift[half][lane] = (bit % 8) as u32;
+        }
+    }
+
+    // SAFETY: avx2 is enabled, and every load reads at most 28 bytes after the start of a group,
+    // which is checked below or padded
+    unsafe {
+        let shuf = [
+            _mm_loadu_si128(shuf[0].as_ptr() as *const __m128i),
+            _mm_loadu_si128(shuf[1].as_ptr() as *const __m128i),
+        ];
+        let shift = [
+            _mm_loadu_si128(shift[0].as_ptr() as *const __m128i),
+            _mm_loadu_si128(shift[1].as_ptr() as *const __m128i),
+        ];
+        let mask = _mm_set1_epi32(mask(bit_width) as i32);
+
+        let unpack_group = |group: *const u8, out: *mut u32| {
+            for half in 0..2 {
+                let b = _mm_loadu_si128(group.add(offset[half]) as *const __m128i);
+                let x = _mm_srlv_epi32(_mm_shuffle_epi8(b, shuf[half]), shift[half]);
+                _mm_storeu_si128(out.add(half * 4) as *mut __m128i, _mm_and_si128(x, mask));
+            }
+        };
+
+        let mut group = 0;
+        while (group + 1) * 8 <= out.len() && group * bit_width + 32 <= run.len() {
+            unpack_group(
+                run.as_ptr().add(group * bit_width),
+                out.as_mut_ptr().add(group * 8),
+            );
+            group += 1;
+        }
+
+        // Copy the last groups into a padded buffer
+        while group * 8 < out.len() {
+            let mut data = [0u8; 32];
+            data[..bit_width].copy_from_slice(&run[group * bit_width..(group + 1) * bit_width]);
+
+            let mut values = [0u32; 8];
+            unpack_group(data.as_ptr(), values.as_mut_ptr());
+
+            let count = (out.len() - group * 8).min(8);
+            out[group * 8..group * 8 + count].copy_from_slice(&values[..count]);
+            group += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, mask};
+    use crate::{encode_to_slice, VarIntDecodeError};
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn write_header(encoded: &mut Vec<u8>, header: u32) {
+        let mut data = [0u8; 5];
+        let len = encode_to_slice(header, &mut data);
+        encoded.extend_from_slice(&data[..len as usize]);
+    }
+
+    /// Encodes runs of 8 or more equal values as repeated values, and everything else as
+    /// bit-packed runs of up to 64 groups.
+    fn encode_reference(values: &[u32], bit_width: usize) -> Vec<u8> {
+        let mut encoded = vec![];
+        let mut i = 0;
+
+        while i < values.len() {
+            let repeats = values[i..].iter().take_while(|v| **v == values[i]).count();
+            if repeats >= 8 {
+                write_header(&mut encoded, (repeats as u32) << 1);
+                encoded.extend_from_slice(&values[i].to_le_bytes()[..bit_width.div_ceil(8)]);
+                i += repeats;
+                continue;
+            }
+
+            let groups = (values.len() - i).div_ceil(8).min(64);
+            write_header(&mut encoded, (groups as u32) << 1 | 1);
+
+            let mut packed = vec![0u8; groups * bit_width + 8];
+            for j in 0..groups * 8 {
+                let value = *values.get(i + j).unwrap_or(&0) as u64;
+                let bit = j * bit_width;
+                for (k, byte) in (value << (bit % 8)).to_le_bytes().iter().enumerate() {
+                    packed[bit / 8 + k] |= byte;
+                }
+            }
+            encoded.extend_from_slice(&packed[..groups * bit_width]);
+            i += groups * 8;
+        }
+
+        encoded
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for bit_width in 0..=32 {
+            for count in [0, 1, 7, 8, 9, 100, 1000] {
+                // mostly random values, with some short runs of small values
+                let mut values: Vec<u32> = (0..count)
+                    .map(|_| match rng.gen_range(0..10) {
+                        0..=2 => 1 & mask(bit_width),
+                        3 => 0,
+                        _ => rng.gen::<u32>() & mask(bit_width),
+                    })
+                    .collect();
+                // and a long repeated run in the middle
+                values.splice(count / 2..count / 2, [mask(bit_width); 20]);
+
+                let encoded = encode_reference(&values, bit_width);
+
+                let mut decoded = vec![0u32; values.len()];
+                assert_eq!(
+                    decode(&encoded, bit_width as u8, &mut decoded).unwrap(),
+                    encoded.len()
+                );
+                assert_eq!(decoded, values, "bit width {}", bit_width);
+
+                // decoding fewer values
+                for len in [values.len() / 3, values.len() - 1] {
+                    let mut decoded = vec![0u32; len];
+                    decode(&encoded, bit_width as u8, &mut decoded).unwrap();
+                    assert_eq!(decoded, values[..len]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        // repeated value of 8 with a bit width of 3
+        assert!(matches!(
+            decode(&[0x06, 0x08], 3, &mut [0; 3]),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        assert!(matches!(
+            decode(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01], 3, &mut [0; 3]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut out = [0u32; 16];
+        // runs end before enough values are decoded
+        assert!(matches!(
+            decode(&[], 3, &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0x06, 0x04], 3, &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        // truncated repeated value and bit-packed run
+        assert!(matches!(
+            decode(&[0x06, 0x04], 9, &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0x03, 0x88, 0xC6], 3, &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0x80], 3, &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,347 @@
+//! Prefix varints, which store the length of the encoded integer in the first byte.
+//!
+//! The number of trailing one bits in the first byte is the number of additional bytes that
+//! follow it. For lengths of 1 to 8 bytes, the value is stored little-endian in the remaining
+//! 7 bits per byte after the length marker. A first byte of `0xFF` is followed by all 64 bits of
+//! the value in little-endian order, for a maximum length of 9 bytes.
+//!
+//! Unlike LEB128, the length is known after reading a single byte, so a value can be decoded with
+//! one unaligned load and a shift.
+
+use core::cmp::min;
+
+use crate::num::{max_len, SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+/// Encodes a single number to a prefix varint.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint.
+///
+/// # Examples
+/// ```
+/// use varint_simd::prefix_varint::encode;
+///
+/// let encoded = encode::<u32>(1337);
+/// assert_eq!(encoded, ([229, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 2));
+/// ```
+#[inline]
+pub fn encode<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
+    let num = num.to_u64();
+    let mut res = [0u8; 16];
+
+    if num < 1 << 56 {
+        let bits = 64 - (num | 1).leading_zeros();
+        let len = bits.div_ceil(7);
+
+        let word = (num << len) | ((1 << (len - 1)) - 1);
+        res[..8].copy_from_slice(&word.to_le_bytes());
+
+        (res, len as u8)
+    } else {
+        res[0] = 0xFF;
+        res[1..9].copy_from_slice(&num.to_le_bytes());
+
+        (res, 9)
+    }
+}
+
+/// Convenience function for encoding a single signed integer in ZigZag format to a prefix varint.
+/// See also: [`encode`]
+#[inline]
+pub fn encode_zigzag<T: SignedVarIntTarget>(num: T) -> ([u8; 16], u8) {
+    encode(T::Unsigned::zigzag(num))
+}
+
+/// Encodes a single number to a prefix varint, and writes the resulting data to the slice.
+/// Returns the number of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode`]
+///
+/// **Panics:** if the slice is too small to contain the varint.
+#[inline]
+pub fn encode_to_slice<T: VarIntTarget>(num: T, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Decodes a single prefix varint from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. For best
+/// performance, provide a slice at least 16 bytes in length, or use the unsafe version directly.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{prefix_varint::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode::<u32>(&[229, 20])?;
+///     assert_eq!(decoded, (1337, 2));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode<T: VarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    let (num, len) = if bytes.len() >= 16 {
+        unsafe { decode_unsafe::<u64>(bytes.as_ptr()) }
+    } else if !bytes.is_empty() {
+        let mut data = [0u8; 16];
+        data[..bytes.len()].copy_from_slice(bytes);
+        unsafe { decode_unsafe::<u64>(data.as_ptr()) }
+    } else {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    };
+
+    if len > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    let result = T::cast_u64(num);
+    if len > max_len::<T>() || result.to_u64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+/// Convenience function for decoding a single prefix varint in ZigZag format from the input
+/// slice. See also: [`decode`]
+#[inline]
+pub fn decode_zigzag<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    decode::<T::Unsigned>(bytes).map(|r| (r.0.unzigzag(), r.1))
+}
+
+/// Decodes only the length of a single prefix varint from the input slice.
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+#[inline]
+pub fn decode_len<T: VarIntTarget>(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(first) => {
+            let len = first.trailing_ones() as usize + 1;
+            if len > max_len::<T>() {
+                Err(VarIntDecodeError::Overflow)
+            } else {
+                Ok(len)
+            }
+        }
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes the length of the next prefix varint
+///
+/// # Safety
+/// The pointer must point to at least one byte of allocated memory.
+#[inline]
+pub unsafe fn decode_len_unsafe(bytes: *const u8) -> usize {
+    (*bytes).trailing_ones() as usize + 1
+}
+
+/// Decodes a single prefix varint from the input pointer. Returns a tuple containing the decoded
+/// number and the number of bytes read.
+///
+/// # Safety
+/// There must be at least 16 bytes of allocated memory after the beginning of the pointer.
+/// Otherwise, there may be undefined behavior. Any data after the end of the varint are ignored.
+/// A truncated value will be returned if the varint represents a number too large for the target
+/// type.
+#[inline]
+pub unsafe fn decode_unsafe<T: VarIntTarget>(bytes: *const u8) -> (T, usize) {
+    let len = decode_len_unsafe(bytes);
+
+    let word = bytes.cast::<u64>().read_unaligned();
+    let short_len = min(len, 8) as u32;
+    // discard the bytes after the varint, then the length marker
+    let unused = 64 - short_len * 8;
+    let short = (word << unused) >> (unused + short_len);
+
+    let num = if T::MAX_VARINT_BYTES <= 5 {
+        short
+    } else {
+        let long = bytes.add(1).cast::<u64>().read_unaligned();
+        if len == 9 {
+            long
+        } else {
+            short
+        }
+    };
+
+    (T::cast_u64(num), len)
+}
+
+/// Decodes four adjacent prefix varints.
+///
+/// Returns a tuple containing the four decoded values, followed by the number of bytes read for
+/// each encoded value.
+///
+/// # Safety
+/// There must be at least 48 bytes of allocated memory after the start of the pointer. Otherwise,
+/// there may be undefined behavior. Any data after the four varints are ignored.
+/// Truncated values will be returned if a varint exceeds the target type's limit.
+#[inline]
+pub unsafe fn decode_four_unsafe<
+    T: VarIntTarget,
+    U: VarIntTarget,
+    V: VarIntTarget,
+    W: VarIntTarget,
+>(
+    bytes: *const u8,
+) -> (T, U, V, W, u8, u8, u8, u8) {
+    // The lengths can all be found from the first byte of each varint, so there is no need to
+    // wait for the values themselves to be decoded before moving on to the next one
+    let first_len = decode_len_unsafe(bytes);
+    let second_len = decode_len_unsafe(bytes.add(first_len));
+    let third_len = decode_len_unsafe(bytes.add(first_len + second_len));
+    let fourth_len = decode_len_unsafe(bytes.add(first_len + second_len + third_len));
+
+    let (first_num, _) = decode_unsafe::<T>(bytes);
+    let (second_num, _) = decode_unsafe::<U>(bytes.add(first_len));
+    let (third_num, _) = decode_unsafe::<V>(bytes.add(first_len + second_len));
+    let (fourth_num, _) = decode_unsafe::<W>(bytes.add(first_len + second_len + third_len));
+
+    (
+        first_num,
+        second_num,
+        third_num,
+        fourth_num,
+        first_len as u8,
+        second_len as u8,
+        third_len as u8,
+        fourth_len as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_four_unsafe, decode_len, decode_zigzag, encode, encode_to_slice};
+    use crate::{VarIntDecodeError, VarIntTarget};
+
+    fn check<T: VarIntTarget>(value: T, encoded: &[u8]) {
+        let mut expected = [0u8; 16];
+        expected[..encoded.len()].copy_from_slice(encoded);
+
+        let a = encode(value);
+        assert_eq!(a.0, expected);
+        assert_eq!(a.1 as usize, encoded.len());
+
+        let roundtrip: (T, usize) = decode(encoded).unwrap();
+        assert_eq!(roundtrip.0, value);
+        assert_eq!(roundtrip.1, encoded.len());
+
+        let roundtrip: (T, usize) = decode(&expected).unwrap();
+        assert_eq!(roundtrip.0, value);
+        assert_eq!(roundtrip.1, encoded.len());
+
+        let len = decode_len::<T>(&expected).unwrap();
+        assert_eq!(len, encoded.len());
+    }
+
+    #[test]
+    fn roundtrip_u8() {
+        check(0u8, &[0x00]);
+        check(127u8, &[0xFE]);
+        check(128u8, &[0x01, 0x02]);
+        check(u8::MAX, &[0xFD, 0x03]);
+    }
+
+    #[test]
+    fn roundtrip_u16() {
+        check(300u16, &[0xB1, 0x04]);
+        check(2u16.pow(14) - 1, &[0xFD, 0xFF]);
+        check(2u16.pow(14), &[0x03, 0x00, 0x02]);
+        check(u16::MAX, &[0xFB, 0xFF, 0x07]);
+    }
+
+    #[test]
+    fn roundtrip_u32() {
+        check(2u32.pow(21) - 1, &[0xFB, 0xFF, 0xFF]);
+        check(2u32.pow(21), &[0x07, 0x00, 0x00, 0x02]);
+        check(2u32.pow(28), &[0x0F, 0x00, 0x00, 0x00, 0x02]);
+        check(u32::MAX, &[0xEF, 0xFF, 0xFF, 0xFF, 0x1F]);
+    }
+
+    #[test]
+    fn roundtrip_u64() {
+        check(
+            2u64.pow(56) - 1,
+            &[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+        check(2u64.pow(56), &[0xFF, 0, 0, 0, 0, 0, 0, 0, 0x01]);
+        check(
+            u64::MAX,
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+
+        for shift in 0..64 {
+            for value in [1u64 << shift, (1u64 << shift) - 1, (1u64 << shift) + 1] {
+                let encoded = encode(value);
+                assert_eq!(
+                    decode::<u64>(&encoded.0).unwrap(),
+                    (value, encoded.1 as usize)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip_zigzag() {
+        for value in [0i64, -1, 1, i64::MIN, i64::MAX] {
+            let encoded = super::encode_zigzag(value);
+            assert_eq!(
+                decode_zigzag::<i64>(&encoded.0[..encoded.1 as usize]).unwrap(),
+                (value, encoded.1 as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let encoded = encode(u8::MAX as u16 + 1);
+        assert!(matches!(
+            decode::<u8>(&encoded.0),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        let encoded = encode(u32::MAX as u64 + 1);
+        assert!(matches!(
+            decode::<u32>(&encoded.0),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_len::<u32>(&[0xFF]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode::<u32>(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode::<u32>(&[0x03, 0x00]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+
+    #[test]
+    fn decode_4x() {
+        let values = [0u64, 300, u32::MAX as u64, u64::MAX];
+        let mut enc = [0u8; 48];
+        let mut idx = 0;
+        for value in values {
+            idx += encode_to_slice(value, &mut enc[idx..]) as usize;
+        }
+
+        let decoded = unsafe { decode_four_unsafe::<u8, u16, u32, u64>(enc.as_ptr()) };
+        assert_eq!(
+            (decoded.0, decoded.1, decoded.2, decoded.3),
+            (0, 300, u32::MAX, u64::MAX)
+        );
+        assert_eq!((decoded.4, decoded.5, decoded.6, decoded.7), (1, 2, 5, 9));
+    }
+}
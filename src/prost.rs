@@ -0,0 +1,188 @@
+//! Drop-in replacements for the varint functions in `prost::encoding`, working on
+//! [`bytes::Buf`] and [`bytes::BufMut`].
+//!
+//! The functions have the same signatures and behavior as their prost counterparts, so they can
+//! be substituted into generated code.
+//!
+//! Requires the `prost` feature.
+//!
+//! # Examples
+//! ```
+//! use varint_simd::prost::{decode_varint, encode_varint, encoded_len_varint};
+//!
+//! let mut buf = Vec::new();
+//! encode_varint(1337, &mut buf);
+//! assert_eq!(buf, [185, 10]);
+//! assert_eq!(encoded_len_varint(1337), 2);
+//!
+//! let mut slice = &buf[..];
+//! assert_eq!(decode_varint(&mut slice).unwrap(), 1337);
+//! assert!(slice.is_empty());
+//! ```
+
+use crate::VarIntDecodeError;
+
+use ::prost::DecodeError;
+use bytes::{Buf, BufMut};
+
+/// Encodes an integer value into LEB128 variable length format, and writes it to the buffer.
+/// Requires SSE2 support.
+///
+/// **Panics:** if the buffer does not have enough remaining space (maximum 10 bytes).
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_varint(value: u64, buf: &mut impl BufMut) {
+    let (data, len) = crate::encode(value);
+    buf.put_slice(&data[..len as usize]);
+}
+
+/// Decodes a LEB128-encoded variable length integer from the buffer, and advances the buffer
+/// past it.
+///
+/// If the varint is contained in the first chunk of the buffer, it is decoded with
+/// [`decode`](crate::decode). Otherwise, it is decoded one byte at a time. As in prost, the
+/// buffer may have been partially advanced if an error is returned.
+#[inline]
+pub fn decode_varint(buf: &mut impl Buf) -> Result<u64, DecodeError> {
+    let bytes = buf.chunk();
+
+    match crate::decode::<u64>(bytes) {
+        Ok((num, len)) if len <= bytes.len() => {
+            buf.advance(len);
+            Ok(num)
+        }
+        // The varint may continue in the next chunk
+        Ok(_) => decode_varint_slow(buf),
+        Err(VarIntDecodeError::NotEnoughBytes) if buf.has_remaining() => decode_varint_slow(buf),
+        Err(_) => Err(invalid_varint()),
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn decode_varint_slow(buf: &mut impl Buf) -> Result<u64, DecodeError> {
+    let mut num = 0;
+
+    for i in 0..core::cmp::min(10, buf.remaining()) {
+        let byte = buf.get_u8();
+        num |= ((byte & 0x7f) as u64) << (i * 7);
+
+        if byte < 0x80 {
+            // Only 1 bit remains for the last byte
+            return if i == 9 && byte > 1 {
+                Err(invalid_varint())
+            } else {
+                Ok(num)
+            };
+        }
+    }
+
+    Err(invalid_varint())
+}
+
+/// Creates the error that prost reports for an invalid varint.
+#[cold]
+fn invalid_varint() -> DecodeError {
+    // prost has no other public constructor for this error
+    #[allow(deprecated)]
+    DecodeError::new("invalid varint")
+}
+
+/// Returns the encoded length of the value in LEB128 variable length format. The returned value
+/// will be between 1 and 10, inclusive.
+#[inline]
+pub fn encoded_len_varint(value: u64) -> usize {
+    crate::num::encoded_len(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the reference implementation used in the benchmarks
+    mod prost_varint {
+        include!("../benches/varint_bench/prost_varint.rs");
+    }
+
+    /// Every value on either side of a length boundary, and the maximum value
+    fn boundaries() -> impl Iterator<Item = u64> {
+        (0..10)
+            .flat_map(|i| [(1u64 << (i * 7)) - 1, 1u64 << (i * 7)])
+            .chain([u64::MAX])
+    }
+
+    fn check(input: &[u8], split: usize) {
+        let split = split.min(input.len());
+
+        let mut expected = (&input[..split]).chain(&input[split..]);
+        let mut actual = (&input[..split]).chain(&input[split..]);
+
+        let expected_num = prost_varint::decode_varint(&mut expected);
+        let actual_num = decode_varint(&mut actual);
+        assert_eq!(expected_num.ok(), actual_num.ok(), "{:?}", input);
+        if expected_num.is_ok() {
+            assert_eq!(expected.remaining(), actual.remaining(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn encode() {
+        for value in boundaries() {
+            let mut expected = vec![];
+            prost_varint::encode_varint(value, &mut expected);
+
+            let mut actual = vec![];
+            encode_varint(value, &mut actual);
+
+            assert_eq!(actual, expected);
+            assert_eq!(encoded_len_varint(value), expected.len());
+        }
+    }
+
+    #[test]
+    fn decode() {
+        for value in boundaries() {
+            let mut encoded = vec![];
+            prost_varint::encode_varint(value, &mut encoded);
+
+            let mut padded = encoded.clone();
+            padded.extend_from_slice(&[0xAB; 20]);
+
+            for split in 0..=encoded.len() {
+                check(&encoded, split);
+                check(&padded, split);
+
+                // truncated
+                check(&encoded[..encoded.len() - 1], split);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let mut inputs = vec![vec![], vec![0x80; 10], vec![0x80; 11], vec![0xFF; 20]];
+        for last in 0..0x80 {
+            let mut input = vec![0xFF; 9];
+            input.push(last);
+            inputs.push(input);
+        }
+
+        for input in inputs {
+            for split in 0..=input.len() {
+                check(&input, split);
+            }
+        }
+
+        let mut buf = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02][..];
+        let expected = ::prost::encoding::decode_varint(&mut &buf[..]).unwrap_err();
+        assert_eq!(
+            decode_varint(&mut buf).unwrap_err().to_string(),
+            expected.to_string()
+        );
+        assert_eq!(
+            decode_varint(&mut &[0x80][..]).unwrap_err().to_string(),
+            "failed to decode Protobuf message: invalid varint"
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! A compact [serde](https://serde.rs) data format built on varints.
+//!
+//! Every integer is stored as a varint: unsigned integers with [`encode`](crate::encode), and
+//! signed integers with [`encode_zigzag`](crate::encode_zigzag). `u128` and `i128` use the same
+//! LEB128 encoding with up to 19 bytes. Other values are stored as follows:
+//!
+//! * `bool`: a single byte, `0` or `1`
+//! * `f32` and `f64`: little-endian IEEE 754 bytes
+//! * `char`: a varint of its code point
+//! * strings, byte arrays, sequences and maps: a varint length followed by the elements
+//! * `Option`: a byte, `0` for `None` or `1` for `Some` followed by the value
+//! * tuples and structs: the fields in order, without a length or field names
+//! * enums: a varint of the variant index, followed by the fields of the variant
+//!
+//! The format is not self-describing, so `deserialize_any` and `deserialize_ignored_any` are not
+//! supported. Strings and byte arrays are borrowed from the input when possible.
+//!
+//! To store selected fields of another format as varints, use the [`varint`] and [`zigzag`]
+//! modules with `#[serde(with = "...")]`, or the [`VarInt`] wrapper.
+//!
+//! Requires the `serde` feature. Serialization to a vector also requires the `std` feature.
+//!
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Entry<'a> {
+//!     id: u64,
+//!     delta: i32,
+//!     name: &'a str,
+//! }
+//!
+//! fn main() -> Result<(), varint_simd::serde::Error> {
+//!     let entry = Entry { id: 300, delta: -2, name: "a" };
+//!
+//!     let encoded = varint_simd::serde::to_vec(&entry)?;
+//!     assert_eq!(encoded, [0xAC, 0x02, 0x03, 0x01, b'a']);
+//!
+//!     let decoded: Entry = varint_simd::serde::from_slice(&encoded)?;
+//!     assert_eq!(decoded, entry);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::VarIntDecodeError;
+
+mod de;
+#[cfg(all(feature = "std", any(target_feature = "sse2", doc)))]
+mod ser;
+pub mod varint;
+pub mod zigzag;
+
+pub use de::{from_slice, Deserializer};
+#[cfg(all(feature = "std", any(target_feature = "sse2", doc)))]
+pub use ser::{to_vec, Serializer};
+pub use varint::VarInt;
+
+/// An error produced while serializing or deserializing.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An integer or length could not be decoded.
+    Decode(VarIntDecodeError),
+    /// A boolean or an `Option` tag was not `0` or `1`.
+    InvalidBool(u8),
+    /// A char was not a valid Unicode scalar value.
+    InvalidChar(u32),
+    /// A string was not valid UTF-8.
+    InvalidUtf8,
+    /// A length did not fit in a `usize`, or exceeded the remaining input.
+    InvalidLength,
+    /// There were bytes left over after deserializing a value.
+    TrailingBytes,
+    /// A sequence or map was serialized without a known length.
+    LengthRequired,
+    /// The type requested self-describing deserialization, which is not supported.
+    NotSelfDescribing,
+    /// A custom error from a `Serialize` or `Deserialize` implementation, whose message was
+    /// discarded because the `std` feature is disabled.
+    Custom,
+    /// A custom error from a `Serialize` or `Deserialize` implementation.
+    #[cfg(feature = "std")]
+    #[cfg_attr(rustc_nightly, doc(cfg(feature = "std")))]
+    Message(String),
+}
+
+impl From<VarIntDecodeError> for Error {
+    fn from(err: VarIntDecodeError) -> Self {
+        Error::Decode(err)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Decode(err) => write!(f, "failed to decode varint: {}", err),
+            Error::InvalidBool(byte) => write!(f, "invalid bool: {}", byte),
+            Error::InvalidChar(num) => write!(f, "invalid char: {:#x}", num),
+            Error::InvalidUtf8 => f.write_str("invalid UTF-8 in string"),
+            Error::InvalidLength => f.write_str("invalid length"),
+            Error::TrailingBytes => f.write_str("trailing bytes after value"),
+            Error::LengthRequired => f.write_str("sequence length is required"),
+            Error::NotSelfDescribing => f.write_str("format is not self-describing"),
+            Error::Custom => f.write_str("custom error"),
+            #[cfg(feature = "std")]
+            Error::Message(msg) => f.write_str(msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(not(feature = "std"))]
+impl ::serde::ser::StdError for Error {}
+
+impl ::serde::ser::Error for Error {
+    #[cfg(feature = "std")]
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn custom<T: core::fmt::Display>(_msg: T) -> Self {
+        Error::Custom
+    }
+}
+
+impl ::serde::de::Error for Error {
+    #[cfg(feature = "std")]
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn custom<T: core::fmt::Display>(_msg: T) -> Self {
+        Error::Custom
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    use ::serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(u32),
+        Point(i64, i64),
+        Rect { width: u16, height: u16 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        id: u64,
+        flag: bool,
+        delta: i8,
+        name: &'a str,
+        #[serde(with = "serde_bytes_compat")]
+        data: &'a [u8],
+        owned: String,
+        ratio: f64,
+        letter: char,
+        wide: u128,
+        wide_signed: i128,
+        parent: Option<u32>,
+        shapes: Vec<Shape>,
+        counts: BTreeMap<u16, i32>,
+        unit: (),
+    }
+
+    // borrows a byte slice through `deserialize_bytes` rather than as a sequence
+    mod serde_bytes_compat {
+        use ::serde::de::{Deserializer, Error, Visitor};
+        use ::serde::Serializer;
+
+        pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(data)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<&'de [u8], D::Error> {
+            struct BytesVisitor;
+
+            impl<'de> Visitor<'de> for BytesVisitor {
+                type Value = &'de [u8];
+
+                fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.write_str("borrowed bytes")
+                }
+
+                fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<&'de [u8], E> {
+                    Ok(v)
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(to_vec(&1337u32).unwrap(), [185, 10]);
+        assert_eq!(to_vec(&200u8).unwrap(), [200, 1]);
+        assert_eq!(to_vec(&-1i64).unwrap(), [1]);
+        assert_eq!(to_vec(&-65i8).unwrap(), [129, 1]);
+        assert_eq!(to_vec(&true).unwrap(), [1]);
+        assert_eq!(to_vec(&Some(3u16)).unwrap(), [1, 3]);
+        assert_eq!(to_vec(&None::<u16>).unwrap(), [0]);
+        assert_eq!(to_vec("hi").unwrap(), [2, b'h', b'i']);
+        assert_eq!(to_vec(&'é').unwrap(), [0xE9, 0x01]);
+        assert_eq!(to_vec(&1.5f32).unwrap(), 1.5f32.to_le_bytes());
+        assert_eq!(to_vec(&vec![1u64, 300]).unwrap(), [2, 1, 0xAC, 0x02]);
+        assert_eq!(to_vec(&Shape::Circle(5)).unwrap(), [1, 5]);
+
+        assert_eq!(from_slice::<u8>(&[200, 1]).unwrap(), 200);
+        assert_eq!(from_slice::<i8>(&[129, 1]).unwrap(), -65);
+        assert_eq!(from_slice::<i64>(&[1]).unwrap(), -1);
+        assert_eq!(from_slice::<char>(&[0xE9, 0x01]).unwrap(), 'é');
+        assert_eq!(
+            from_slice::<Shape>(&[3, 2, 3]).unwrap(),
+            Shape::Rect {
+                width: 2,
+                height: 3
+            }
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let owned_name = String::from("borrowed");
+        let data = [1u8, 2, 3, 255];
+        let record = Record {
+            id: u64::MAX,
+            flag: true,
+            delta: i8::MIN,
+            name: &owned_name,
+            data: &data,
+            owned: "owned".into(),
+            ratio: -0.25,
+            letter: '\u{10FFFF}',
+            wide: u128::MAX,
+            wide_signed: i128::MIN,
+            parent: Some(7),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(u32::MAX),
+                Shape::Point(i64::MIN, i64::MAX),
+                Shape::Rect {
+                    width: 1,
+                    height: u16::MAX,
+                },
+            ],
+            counts: [(1, -1), (300, i32::MAX)].into_iter().collect(),
+            unit: (),
+        };
+
+        let encoded = to_vec(&record).unwrap();
+        let decoded: Record = from_slice(&encoded).unwrap();
+        assert_eq!(decoded, record);
+
+        // the borrowed fields point into the input
+        let range = encoded.as_ptr_range();
+        assert!(range.contains(&decoded.name.as_ptr()));
+        assert!(range.contains(&decoded.data.as_ptr()));
+    }
+
+    #[test]
+    fn deserializer() {
+        let mut encoded = to_vec(&(1u32, "a")).unwrap();
+        encoded.extend_from_slice(&to_vec(&-2i32).unwrap());
+
+        assert!(matches!(
+            from_slice::<(u32, &str)>(&encoded),
+            Err(Error::TrailingBytes)
+        ));
+
+        let mut deserializer = Deserializer::from_slice(&encoded);
+        let first = <(u32, &str)>::deserialize(&mut deserializer).unwrap();
+        assert_eq!(first, (1, "a"));
+        assert_eq!(i32::deserialize(&mut deserializer).unwrap(), -2);
+        assert!(deserializer.remaining().is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            from_slice::<bool>(&[2]),
+            Err(Error::InvalidBool(2))
+        ));
+        assert!(matches!(
+            from_slice::<Option<u8>>(&[3, 0]),
+            Err(Error::InvalidBool(3))
+        ));
+        assert!(matches!(
+            from_slice::<char>(&[0x80, 0xB0, 0x03]),
+            Err(Error::InvalidChar(0xD800))
+        ));
+        assert!(matches!(
+            from_slice::<&str>(&[1, 0xFF]),
+            Err(Error::InvalidUtf8)
+        ));
+        assert!(matches!(
+            from_slice::<&str>(&[5, b'a']),
+            Err(Error::InvalidLength)
+        ));
+        assert!(matches!(
+            from_slice::<u8>(&[0x80, 0x02]),
+            Err(Error::Decode(VarIntDecodeError::Overflow))
+        ));
+        assert!(matches!(
+            from_slice::<u32>(&[0x80]),
+            Err(Error::Decode(VarIntDecodeError::NotEnoughBytes))
+        ));
+        assert!(matches!(
+            from_slice::<Vec<u32>>(&[3, 1, 2]),
+            Err(Error::Decode(VarIntDecodeError::NotEnoughBytes))
+        ));
+        assert!(matches!(from_slice::<Shape>(&[4]), Err(Error::Message(_))));
+    }
+}
@@ -0,0 +1,171 @@
+//! Serializes an unsigned integer field as the varint bytes produced by
+//! [`encode`](crate::encode), for use with `#[serde(with = "varint_simd::serde::varint")]`.
+//!
+//! The varint is written as a byte string with `serialize_bytes`, so any length prefix that the
+//! format adds to byte strings is also written. When deserializing, byte strings and sequences of
+//! bytes are both accepted, and the varint must span the entire byte string.
+//!
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Entry {
+//!     #[serde(with = "varint_simd::serde::varint")]
+//!     id: u64,
+//! }
+//!
+//! let encoded = varint_simd::serde::to_vec(&Entry { id: 300 }).unwrap();
+//! assert_eq!(encoded, [2, 0xAC, 0x02]);
+//!
+//! let decoded: Entry = varint_simd::serde::from_slice(&encoded).unwrap();
+//! assert_eq!(decoded, Entry { id: 300 });
+//! ```
+
+use crate::num::VarIntTarget;
+
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+#[cfg(any(target_feature = "sse2", doc))]
+use ::serde::ser::{Serialize, Serializer};
+use core::marker::PhantomData;
+
+/// Serializes `value` as a varint byte string.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn serialize<T: VarIntTarget, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (data, len) = crate::encode(*value);
+    serializer.serialize_bytes(&data[..len as usize])
+}
+
+/// Deserializes a value from a varint byte string.
+#[inline]
+pub fn deserialize<'de, T: VarIntTarget, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_bytes(VarIntVisitor(PhantomData))
+}
+
+/// A wrapper that serializes an unsigned integer as a varint byte string.
+///
+/// This is useful for integers inside other types, such as `Vec<VarInt<u64>>`, where the `with`
+/// attribute cannot be used. See the [module documentation](self) for the format.
+///
+/// # Examples
+/// ```
+/// use varint_simd::serde::VarInt;
+///
+/// let encoded = varint_simd::serde::to_vec(&VarInt(1337u32)).unwrap();
+/// assert_eq!(encoded, [2, 185, 10]);
+///
+/// let decoded: VarInt<u32> = varint_simd::serde::from_slice(&encoded).unwrap();
+/// assert_eq!(decoded, VarInt(1337));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VarInt<T>(pub T);
+
+impl<T> From<T> for VarInt<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        VarInt(value)
+    }
+}
+
+#[cfg(any(target_feature = "sse2", doc))]
+impl<T: VarIntTarget> Serialize for VarInt<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: VarIntTarget> Deserialize<'de> for VarInt<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(VarInt)
+    }
+}
+
+struct VarIntVisitor<T>(PhantomData<T>);
+
+impl<'de, T: VarIntTarget> Visitor<'de> for VarIntVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a varint of at most {} bytes", T::MAX_VARINT_BYTES)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        let (num, len) = crate::decode::<T>(v).map_err(E::custom)?;
+        if len != v.len() {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        Ok(num)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut data = [0u8; 16];
+        let mut len = 0;
+
+        while let Some(byte) = seq.next_element::<u8>()? {
+            if len == T::MAX_VARINT_BYTES as usize {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+
+            data[len] = byte;
+            len += 1;
+        }
+
+        self.visit_bytes(&data[..len])
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    use ::serde::de::value::{BytesDeserializer, Error, SeqDeserializer};
+
+    fn from_bytes<T: VarIntTarget>(bytes: &[u8]) -> Result<T, Error> {
+        deserialize(BytesDeserializer::new(bytes))
+    }
+
+    fn from_seq<T: VarIntTarget>(bytes: &[u8]) -> Result<T, Error> {
+        deserialize(SeqDeserializer::new(bytes.iter().copied()))
+    }
+
+    #[test]
+    fn roundtrip() {
+        for value in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let encoded = crate::serde::to_vec(&VarInt(value)).unwrap();
+            let (data, len) = crate::encode(value);
+            assert_eq!(encoded[0], len);
+            assert_eq!(encoded[1..], data[..len as usize]);
+
+            let decoded: VarInt<u64> = crate::serde::from_slice(&encoded).unwrap();
+            assert_eq!(decoded, VarInt(value));
+
+            assert_eq!(from_bytes::<u64>(&data[..len as usize]).unwrap(), value);
+            assert_eq!(from_seq::<u64>(&data[..len as usize]).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        // trailing byte after the varint
+        assert!(from_bytes::<u32>(&[0x01, 0x01]).is_err());
+        assert!(from_seq::<u32>(&[0x01, 0x01]).is_err());
+
+        // overflow
+        assert!(from_bytes::<u8>(&[0x80, 0x02]).is_err());
+        assert!(from_seq::<u16>(&[0x80, 0x80, 0x80, 0x01]).is_err());
+
+        // not enough bytes
+        assert!(from_bytes::<u32>(&[]).is_err());
+        assert!(from_bytes::<u32>(&[0x80]).is_err());
+    }
+}
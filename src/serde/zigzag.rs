@@ -0,0 +1,71 @@
+//! Serializes a signed integer field as the ZigZag varint bytes produced by
+//! [`encode_zigzag`](crate::encode_zigzag), for use with
+//! `#[serde(with = "varint_simd::serde::zigzag")]`.
+//!
+//! The format is otherwise the same as [`varint`](super::varint).
+//!
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Entry {
+//!     #[serde(with = "varint_simd::serde::zigzag")]
+//!     delta: i32,
+//! }
+//!
+//! let encoded = varint_simd::serde::to_vec(&Entry { delta: -2 }).unwrap();
+//! assert_eq!(encoded, [1, 0x03]);
+//!
+//! let decoded: Entry = varint_simd::serde::from_slice(&encoded).unwrap();
+//! assert_eq!(decoded, Entry { delta: -2 });
+//! ```
+
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+
+use ::serde::de::Deserializer;
+#[cfg(any(target_feature = "sse2", doc))]
+use ::serde::ser::Serializer;
+
+/// Serializes `value` as a ZigZag varint byte string.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn serialize<T: SignedVarIntTarget, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (data, len) = crate::encode_zigzag(*value);
+    serializer.serialize_bytes(&data[..len as usize])
+}
+
+/// Deserializes a value from a ZigZag varint byte string.
+#[inline]
+pub fn deserialize<'de, T: SignedVarIntTarget, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    super::varint::deserialize::<T::Unsigned, D>(deserializer).map(|num| num.unzigzag())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::serde::de::value::{BytesDeserializer, Error};
+
+    #[test]
+    fn roundtrip() {
+        for value in [0i64, -1, 1, -64, 64, i32::MIN as i64, i64::MIN, i64::MAX] {
+            let (data, len) = crate::encode_zigzag(value);
+            let decoded: Result<i64, Error> =
+                deserialize(BytesDeserializer::new(&data[..len as usize]));
+            assert_eq!(decoded.unwrap(), value);
+        }
+
+        let decoded: Result<i8, Error> = deserialize(BytesDeserializer::new(&[0xFF, 0x01]));
+        assert_eq!(decoded.unwrap(), i8::MIN);
+
+        let decoded: Result<i8, Error> = deserialize(BytesDeserializer::new(&[0x80, 0x02]));
+        assert!(decoded.is_err());
+    }
+}
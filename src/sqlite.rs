@@ -0,0 +1,279 @@
+//! SQLite4 variable-length integers, as used in SQLite4 LSM files.
+//!
+//! The first byte determines the length of the encoded integer:
+//!
+//! | First byte `A0` | Length | Value                                      |
+//! |-----------------|--------|--------------------------------------------|
+//! | 0 to 240        | 1      | `A0`                                       |
+//! | 241 to 248      | 2      | `240 + 256 * (A0 - 241) + A1`              |
+//! | 249             | 3      | `2288 + 256 * A1 + A2`                     |
+//! | 250 to 255      | 4 to 9 | `A1..` as a 3 to 8 byte big-endian integer |
+//!
+//! Unlike LEB128, encoded values sort lexicographically in the same order as the numbers they
+//! represent.
+
+use crate::num::{max_len, VarIntTarget};
+use crate::VarIntDecodeError;
+
+/// Encodes a single number to a SQLite4 varint.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// varint.
+///
+/// # Examples
+/// ```
+/// use varint_simd::sqlite::encode;
+///
+/// let encoded = encode::<u32>(1337);
+/// assert_eq!(encoded, ([245, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 2));
+/// ```
+#[inline]
+pub fn encode<T: VarIntTarget>(num: T) -> ([u8; 16], u8) {
+    let num = num.to_u64();
+    let mut res = [0u8; 16];
+
+    let len = if num <= 240 {
+        res[0] = num as u8;
+        1
+    } else if num <= 2287 {
+        res[0] = ((num - 240) / 256 + 241) as u8;
+        res[1] = ((num - 240) % 256) as u8;
+        2
+    } else if num <= 67823 {
+        res[0] = 249;
+        res[1] = ((num - 2288) / 256) as u8;
+        res[2] = ((num - 2288) % 256) as u8;
+        3
+    } else {
+        // between 3 and 8 bytes of big-endian data
+        let data_len = 8 - num.leading_zeros() / 8;
+        let data_len = data_len.max(3);
+
+        res[0] = (247 + data_len) as u8;
+        res[1..9].copy_from_slice(&(num << ((8 - data_len) * 8)).to_be_bytes());
+        data_len + 1
+    };
+
+    (res, len as u8)
+}
+
+/// Encodes a single number to a SQLite4 varint, and writes the resulting data to the slice.
+/// Returns the number of bytes written (maximum 9 bytes).
+///
+/// See also: [`encode`]
+///
+/// **Panics:** if the slice is too small to contain the varint.
+#[inline]
+pub fn encode_to_slice<T: VarIntTarget>(num: T, slice: &mut [u8]) -> u8 {
+    let (data, size) = encode(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Decodes a single SQLite4 varint from the input slice.
+///
+/// Produces a tuple containing the decoded number and the number of bytes read. For best
+/// performance, provide a slice at least 16 bytes in length, or use the unsafe version directly.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{sqlite::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let decoded = decode::<u32>(&[245, 73])?;
+///     assert_eq!(decoded, (1337, 2));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode<T: VarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    let (num, len) = if bytes.len() >= 16 {
+        unsafe { decode_unsafe::<u64>(bytes.as_ptr()) }
+    } else if !bytes.is_empty() {
+        let mut data = [0u8; 16];
+        data[..bytes.len()].copy_from_slice(bytes);
+        unsafe { decode_unsafe::<u64>(data.as_ptr()) }
+    } else {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    };
+
+    if len > bytes.len() {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+
+    let result = T::cast_u64(num);
+    if len > max_len::<T>() || result.to_u64() != num {
+        Err(VarIntDecodeError::Overflow)
+    } else {
+        Ok((result, len))
+    }
+}
+
+/// Decodes only the length of a single SQLite4 varint from the input slice.
+///
+/// Only the first byte is inspected, so the returned length may exceed the length of the slice.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{sqlite::decode_len, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(decode_len::<u64>(&[249])?, 3);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_len<T: VarIntTarget>(bytes: &[u8]) -> Result<usize, VarIntDecodeError> {
+    match bytes.first() {
+        Some(first) => {
+            let len = unsafe { decode_len_unsafe(first) };
+            if len > max_len::<T>() {
+                Err(VarIntDecodeError::Overflow)
+            } else {
+                Ok(len)
+            }
+        }
+        None => Err(VarIntDecodeError::NotEnoughBytes),
+    }
+}
+
+/// Decodes the length of the next SQLite4 varint
+///
+/// # Safety
+/// The pointer must point to at least one byte of allocated memory.
+#[inline]
+pub unsafe fn decode_len_unsafe(bytes: *const u8) -> usize {
+    match *bytes {
+        0..=240 => 1,
+        241..=248 => 2,
+        249 => 3,
+        first => first as usize - 246,
+    }
+}
+
+/// Decodes a single SQLite4 varint from the input pointer. Returns a tuple containing the decoded
+/// number and the number of bytes read.
+///
+/// # Safety
+/// There must be at least 16 bytes of allocated memory after the beginning of the pointer.
+/// Otherwise, there may be undefined behavior. Any data after the end of the varint are ignored.
+/// A truncated value will be returned if the varint represents a number too large for the target
+/// type.
+#[inline]
+pub unsafe fn decode_unsafe<T: VarIntTarget>(bytes: *const u8) -> (T, usize) {
+    let first = *bytes as u64;
+
+    let (num, len) = match first {
+        0..=240 => (first, 1),
+        241..=248 => (240 + 256 * (first - 241) + *bytes.add(1) as u64, 2),
+        249 => (2288 + 256 * *bytes.add(1) as u64 + *bytes.add(2) as u64, 3),
+        _ => {
+            let data_len = first as u32 - 247;
+            let data = u64::from_be(bytes.add(1).cast::<u64>().read_unaligned());
+            (data >> ((8 - data_len) * 8), data_len as usize + 1)
+        }
+    };
+
+    (T::cast_u64(num), len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_len, encode, encode_to_slice};
+    use crate::{VarIntDecodeError, VarIntTarget};
+
+    fn check<T: VarIntTarget>(value: T, encoded: &[u8]) {
+        let mut expected = [0u8; 16];
+        expected[..encoded.len()].copy_from_slice(encoded);
+
+        let a = encode(value);
+        assert_eq!(a.0, expected);
+        assert_eq!(a.1 as usize, encoded.len());
+
+        let roundtrip: (T, usize) = decode(encoded).unwrap();
+        assert_eq!(roundtrip.0, value);
+        assert_eq!(roundtrip.1, encoded.len());
+
+        let roundtrip: (T, usize) = decode(&expected).unwrap();
+        assert_eq!(roundtrip.0, value);
+        assert_eq!(roundtrip.1, encoded.len());
+
+        let len = decode_len::<T>(&expected).unwrap();
+        assert_eq!(len, encoded.len());
+    }
+
+    #[test]
+    fn roundtrip() {
+        check(0u8, &[0]);
+        check(240u8, &[240]);
+        check(241u8, &[241, 1]);
+        check(u8::MAX, &[241, 15]);
+
+        check(2287u16, &[248, 255]);
+        check(2288u16, &[249, 0, 0]);
+        check(u16::MAX, &[249, 247, 15]);
+
+        check(67823u32, &[249, 255, 255]);
+        check(67824u32, &[250, 1, 8, 240]);
+        check(2u32.pow(24) - 1, &[250, 255, 255, 255]);
+        check(2u32.pow(24), &[251, 1, 0, 0, 0]);
+        check(u32::MAX, &[251, 255, 255, 255, 255]);
+
+        check(2u64.pow(32), &[252, 1, 0, 0, 0, 0]);
+        check(2u64.pow(40), &[253, 1, 0, 0, 0, 0, 0]);
+        check(2u64.pow(48), &[254, 1, 0, 0, 0, 0, 0, 0]);
+        check(2u64.pow(56), &[255, 1, 0, 0, 0, 0, 0, 0, 0]);
+        check(u64::MAX, &[255, 255, 255, 255, 255, 255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn sorts_lexicographically() {
+        let mut values = vec![0u64, 1, 239, 240, 241, 2287, 2288, 67823, 67824, u64::MAX];
+        for shift in 0..64 {
+            values.push(1 << shift);
+            values.push((1 << shift) - 1);
+        }
+        values.sort_unstable();
+
+        let encoded: Vec<_> = values
+            .iter()
+            .map(|num| {
+                let (data, len) = encode(*num);
+                data[..len as usize].to_vec()
+            })
+            .collect();
+
+        for (i, window) in encoded.windows(2).enumerate() {
+            assert!(window[0] <= window[1], "{} {}", values[i], values[i + 1]);
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let mut encoded = [0u8; 16];
+        encode_to_slice(u32::MAX as u64 + 1, &mut encoded);
+        assert!(matches!(
+            decode::<u32>(&encoded),
+            Err(VarIntDecodeError::Overflow)
+        ));
+
+        encode_to_slice(256u16, &mut encoded);
+        assert!(matches!(
+            decode::<u8>(&encoded),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode::<u64>(&[]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode::<u64>(&[250, 1, 2]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,283 @@
+//! Stream VByte encoding for `u32` arrays.
+//!
+//! The control bytes for all values are stored first, one byte for every four values, followed
+//! by the data bytes. Each control byte holds four 2-bit fields, lowest bits first, containing the
+//! number of bytes used by the corresponding value minus one, and each value is stored as a 1 to 4
+//! byte little-endian integer. Keeping the control bytes apart from the data lets the decoder find
+//! the layout of the next group without waiting on the previous one.
+//!
+//! See: Daniel Lemire, Nathan Kurz, Christoph Rupp - Stream VByte: Faster Byte-Oriented Integer
+//! Compression, Information Processing Letters 130, 2018: <https://arxiv.org/abs/1709.08990>
+
+#[cfg(all(target_arch = "x86", target_feature = "ssse3"))]
+use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "ssse3"))]
+use core::arch::x86_64::*;
+
+use crate::group_varint::byte_len;
+#[cfg(target_feature = "ssse3")]
+use crate::group_varint::lookup;
+use crate::VarIntDecodeError;
+
+/// Returns the maximum number of bytes needed to encode `count` values.
+#[inline]
+pub const fn max_encoded_len(count: usize) -> usize {
+    count.div_ceil(4) + count * 4
+}
+
+/// Encodes a slice of numbers, and writes the resulting data to the output slice. Returns the
+/// number of bytes written.
+///
+/// See also: [`max_encoded_len`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+///
+/// # Examples
+/// ```
+/// use varint_simd::stream_vbyte::encode;
+///
+/// let mut out = [0u8; 17];
+/// let len = encode(&[1, 1337, 0, 70000], &mut out);
+/// assert_eq!(&out[..len], &[0b10_00_01_00, 1, 57, 5, 0, 112, 17, 1]);
+/// ```
+#[inline]
+pub fn encode(values: &[u32], out: &mut [u8]) -> usize {
+    encode_inner::<false>(values, out)
+}
+
+/// Encodes a slice of numbers as the differences between consecutive values, starting from zero.
+/// This is most effective on sorted input. Returns the number of bytes written.
+///
+/// See also: [`encode`]
+///
+/// **Panics:** if the output slice is too small to contain the encoded data.
+#[inline]
+pub fn encode_delta(values: &[u32], out: &mut [u8]) -> usize {
+    encode_inner::<true>(values, out)
+}
+
+#[inline(always)]
+fn encode_inner<const DELTA: bool>(values: &[u32], out: &mut [u8]) -> usize {
+    let control_len = values.len().div_ceil(4);
+    let (control, data) = out.split_at_mut(control_len);
+    control.fill(0);
+
+    let mut pos = 0;
+    let mut prev = 0u32;
+    for (i, num) in values.iter().enumerate() {
+        let num = if DELTA {
+            let delta = num.wrapping_sub(prev);
+            prev = *num;
+            delta
+        } else {
+            *num
+        };
+
+        let len = byte_len(num);
+        data[pos..pos + len].copy_from_slice(&num.to_le_bytes()[..len]);
+        control[i / 4] |= ((len - 1) as u8) << ((i % 4) * 2);
+        pos += len;
+    }
+
+    control_len + pos
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode`]. Returns the number
+/// of bytes read.
+///
+/// Groups of four values are decoded with a single shuffle when SSSE3 is available, and the last
+/// few values are decoded one at a time.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{stream_vbyte::decode, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let mut out = [0u32; 4];
+///     let len = decode(&[0b10_00_01_00, 1, 57, 5, 0, 112, 17, 1], &mut out)?;
+///     assert_eq!(out, [1, 1337, 0, 70000]);
+///     assert_eq!(len, 8);
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    decode_inner::<false>(bytes, out)
+}
+
+/// Decodes `out.len()` numbers from the input slice, as encoded by [`encode_delta`]. Returns the
+/// number of bytes read.
+///
+/// The differences are summed within each group of four values using SIMD when SSSE3 is
+/// available.
+#[inline]
+pub fn decode_delta(bytes: &[u8], out: &mut [u32]) -> Result<usize, VarIntDecodeError> {
+    decode_inner::<true>(bytes, out)
+}
+
+#[inline(always)]
+fn decode_inner<const DELTA: bool>(
+    bytes: &[u8],
+    out: &mut [u32],
+) -> Result<usize, VarIntDecodeError> {
+    let control_len = out.len().div_ceil(4);
+    if bytes.len() < control_len {
+        return Err(VarIntDecodeError::NotEnoughBytes);
+    }
+    let (control, data) = bytes.split_at(control_len);
+
+    let mut pos = 0;
+    #[allow(unused_mut)]
+    let mut start = 0;
+
+    #[cfg(target_feature = "ssse3")]
+    unsafe {
+        let mut prev = _mm_setzero_si128();
+
+        // Stop early enough that a full 16-byte load never reads past the end of the input
+        while start + 4 <= out.len() && data.len() - pos >= 16 {
+            let c = *control.get_unchecked(start / 4) as usize;
+            let b = _mm_loadu_si128(data.as_ptr().add(pos) as *const __m128i);
+
+            let shuf = *lookup::LOOKUP_GROUP_VEC.get_unchecked(c);
+            let mut x = _mm_shuffle_epi8(b, shuf);
+
+            if DELTA {
+                x = prefix_sum_u32(x, prev);
+                prev = _mm_shuffle_epi32(x, 0b11111111);
+            }
+
+            _mm_storeu_si128(out.as_mut_ptr().add(start) as *mut __m128i, x);
+
+            pos += *lookup::LOOKUP_GROUP_LEN.get_unchecked(c) as usize;
+            start += 4;
+        }
+    }
+
+    let mut prev = if start > 0 { out[start - 1] } else { 0 };
+    for (i, num) in out.iter_mut().enumerate().skip(start) {
+        let len = ((control[i / 4] >> ((i % 4) * 2)) & 0b11) as usize + 1;
+        let src = data
+            .get(pos..pos + len)
+            .ok_or(VarIntDecodeError::NotEnoughBytes)?;
+
+        let mut buf = [0u8; 4];
+        buf[..len].copy_from_slice(src);
+        *num = u32::from_le_bytes(buf);
+
+        if DELTA {
+            *num = num.wrapping_add(prev);
+            prev = *num;
+        }
+
+        pos += len;
+    }
+
+    Ok(control_len + pos)
+}
+
+/// Computes the inclusive prefix sum of the four 32-bit lanes, and adds `prev` to each lane.
+#[inline(always)]
+#[cfg(target_feature = "ssse3")]
+pub(crate) unsafe fn prefix_sum_u32(x: __m128i, prev: __m128i) -> __m128i {
+    let x = _mm_add_epi32(x, _mm_bslli_si128(x, 4));
+    let x = _mm_add_epi32(x, _mm_bslli_si128(x, 8));
+    _mm_add_epi32(x, prev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_delta, encode, encode_delta, max_encoded_len};
+    use crate::VarIntDecodeError;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Straightforward implementation of the format to check against
+    fn encode_reference(values: &[u32]) -> Vec<u8> {
+        let mut control = vec![0u8; values.len().div_ceil(4)];
+        let mut data = Vec::new();
+
+        for (i, num) in values.iter().enumerate() {
+            let len = match *num {
+                0..=0xff => 1,
+                0x100..=0xffff => 2,
+                0x10000..=0xffffff => 3,
+                _ => 4,
+            };
+            control[i / 4] |= (len as u8 - 1) << ((i % 4) * 2);
+            data.extend_from_slice(&num.to_le_bytes()[..len]);
+        }
+
+        control.extend(data);
+        control
+    }
+
+    fn check(values: &[u32]) {
+        let reference = encode_reference(values);
+
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(values, &mut encoded);
+        assert_eq!(&encoded[..len], &reference[..]);
+
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded[..len], &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        // with padding, so the SIMD path is taken for every full group
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+
+        let len = encode_delta(values, &mut encoded);
+        let mut decoded = vec![0u32; values.len()];
+        assert_eq!(decode_delta(&encoded, &mut decoded).unwrap(), len);
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for count in 0..40 {
+            let values: Vec<u32> = (0..count)
+                .map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+                .collect();
+            check(&values);
+        }
+
+        let values: Vec<u32> = (0..1000)
+            .map(|_| rng.gen::<u32>() >> rng.gen_range(0..32))
+            .collect();
+        check(&values);
+
+        check(&[0, u32::MAX, 0, u32::MAX, 1, 2, 3]);
+    }
+
+    #[test]
+    fn roundtrip_sorted() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut values: Vec<u32> = (0..1000).map(|_| rng.gen()).collect();
+        values.sort_unstable();
+        check(&values);
+
+        let mut encoded = vec![0u8; max_encoded_len(values.len())];
+        let len = encode(&values, &mut encoded);
+        let delta_len = encode_delta(&values, &mut encoded);
+        assert!(delta_len < len);
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        let mut out = [0u32; 5];
+        assert!(matches!(
+            decode(&[0], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode(&[0, 0, 1, 2, 3, 4], &mut out),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+//! Integer and field header helpers for the Thrift compact protocol.
+//!
+//! The compact protocol stores `i16`, `i32` and `i64` values as ZigZag varints. Each field of a
+//! struct begins with a header byte holding the field type in the low 4 bits. If the field id is
+//! between 1 and 15 greater than the previous field id, the difference is stored in the high 4
+//! bits. Otherwise, the high bits are zero and the field id follows as a ZigZag varint. A header
+//! byte of zero marks the end of the struct.
+//!
+//! See: <https://github.com/apache/thrift/blob/master/doc/specs/thrift-compact-protocol.md>
+
+use crate::num::SignedVarIntTarget;
+use crate::VarIntDecodeError;
+
+/// The header byte that marks the end of a struct
+pub const STOP: u8 = 0;
+
+/// The type of a field, as stored in the low 4 bits of a field header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CompactType {
+    BooleanTrue = 1,
+    BooleanFalse = 2,
+    Byte = 3,
+    I16 = 4,
+    I32 = 5,
+    I64 = 6,
+    Double = 7,
+    Binary = 8,
+    List = 9,
+    Set = 10,
+    Map = 11,
+    Struct = 12,
+}
+
+impl CompactType {
+    /// Converts from the 4-bit type id, returning `None` if it is not a valid field type
+    #[inline]
+    pub fn from_u8(num: u8) -> Option<Self> {
+        Some(match num {
+            1 => CompactType::BooleanTrue,
+            2 => CompactType::BooleanFalse,
+            3 => CompactType::Byte,
+            4 => CompactType::I16,
+            5 => CompactType::I32,
+            6 => CompactType::I64,
+            7 => CompactType::Double,
+            8 => CompactType::Binary,
+            9 => CompactType::List,
+            10 => CompactType::Set,
+            11 => CompactType::Map,
+            12 => CompactType::Struct,
+            _ => return None,
+        })
+    }
+}
+
+/// A decoded field header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldHeader {
+    pub field_type: CompactType,
+    pub field_id: i16,
+}
+
+/// Encodes a field header, using the short form if possible. `last_field_id` is the id of the
+/// previous field in the struct, or zero for the first field.
+///
+/// Produces a tuple, with the encoded data followed by the number of bytes used to encode the
+/// header.
+///
+/// # Examples
+/// ```
+/// use varint_simd::thrift_compact::{encode_field_header, CompactType, FieldHeader};
+///
+/// let header = FieldHeader { field_type: CompactType::I32, field_id: 3 };
+/// let (data, len) = encode_field_header(header, 1);
+/// assert_eq!(&data[..len as usize], &[0x25]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn encode_field_header(header: FieldHeader, last_field_id: i16) -> ([u8; 16], u8) {
+    let delta = header.field_id as i32 - last_field_id as i32;
+    let field_type = header.field_type as u8;
+
+    if (1..=15).contains(&delta) {
+        let mut res = [0u8; 16];
+        res[0] = (delta as u8) << 4 | field_type;
+        (res, 1)
+    } else {
+        let (id, len) = crate::encode_zigzag(header.field_id);
+        let mut res = [0u8; 16];
+        res[0] = field_type;
+        res[1..].copy_from_slice(&id[..15]);
+        (res, len + 1)
+    }
+}
+
+/// Decodes a field header from the input slice. `last_field_id` is the id of the previous field in
+/// the struct, or zero for the first field.
+///
+/// Produces a tuple containing the decoded header and the number of bytes read. The header is
+/// `None` if the end of the struct was reached.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{thrift_compact::{decode_field_header, CompactType, FieldHeader}, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     let (header, len) = decode_field_header(&[0x25], 1)?;
+///     assert_eq!(header, Some(FieldHeader { field_type: CompactType::I32, field_id: 3 }));
+///     assert_eq!(len, 1);
+///
+///     assert_eq!(decode_field_header(&[0x00], 3)?, (None, 1));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn decode_field_header(
+    bytes: &[u8],
+    last_field_id: i16,
+) -> Result<(Option<FieldHeader>, usize), VarIntDecodeError> {
+    let first = *bytes.first().ok_or(VarIntDecodeError::NotEnoughBytes)?;
+    if first == STOP {
+        return Ok((None, 1));
+    }
+
+    let field_type = CompactType::from_u8(first & 0x0F).ok_or(VarIntDecodeError::Invalid)?;
+    let delta = first >> 4;
+
+    let (field_id, len) = if delta != 0 {
+        let field_id = last_field_id
+            .checked_add(delta as i16)
+            .ok_or(VarIntDecodeError::Overflow)?;
+        (field_id, 1)
+    } else {
+        let (field_id, len) = read_i16(&bytes[1..])?;
+        (field_id, len + 1)
+    };
+
+    Ok((
+        Some(FieldHeader {
+            field_type,
+            field_id,
+        }),
+        len,
+    ))
+}
+
+#[inline(always)]
+fn read<T: SignedVarIntTarget>(bytes: &[u8]) -> Result<(T, usize), VarIntDecodeError> {
+    let (num, len) = crate::decode_zigzag::<T>(bytes)?;
+    if len > bytes.len() {
+        Err(VarIntDecodeError::NotEnoughBytes)
+    } else {
+        Ok((num, len))
+    }
+}
+
+#[inline(always)]
+#[cfg(any(target_feature = "sse2", doc))]
+fn write<T: SignedVarIntTarget>(num: T, slice: &mut [u8]) -> u8 {
+    let (data, size) = crate::encode_zigzag(num);
+    slice[..size as usize].copy_from_slice(&data[..size as usize]);
+
+    size
+}
+
+/// Reads an `i16` from the input slice. Returns a tuple containing the decoded number and the
+/// number of bytes read.
+///
+/// # Examples
+/// ```
+/// use varint_simd::{thrift_compact::read_i16, VarIntDecodeError};
+///
+/// fn main() -> Result<(), VarIntDecodeError> {
+///     assert_eq!(read_i16(&[0xF1, 0x14])?, (-1337, 2));
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn read_i16(bytes: &[u8]) -> Result<(i16, usize), VarIntDecodeError> {
+    read(bytes)
+}
+
+/// Reads an `i32` from the input slice. Returns a tuple containing the decoded number and the
+/// number of bytes read.
+///
+/// See also: [`read_i16`]
+#[inline]
+pub fn read_i32(bytes: &[u8]) -> Result<(i32, usize), VarIntDecodeError> {
+    read(bytes)
+}
+
+/// Reads an `i64` from the input slice. Returns a tuple containing the decoded number and the
+/// number of bytes read.
+///
+/// See also: [`read_i16`]
+#[inline]
+pub fn read_i64(bytes: &[u8]) -> Result<(i64, usize), VarIntDecodeError> {
+    read(bytes)
+}
+
+/// Writes an `i16` to the slice. Returns the number of bytes written (maximum 3 bytes).
+///
+/// **Panics:** if the slice is too small to contain the integer.
+///
+/// # Examples
+/// ```
+/// use varint_simd::thrift_compact::write_i16;
+///
+/// let mut data = [0u8; 3];
+/// let len = write_i16(-1337, &mut data);
+/// assert_eq!(&data[..len as usize], &[0xF1, 0x14]);
+/// ```
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn write_i16(num: i16, slice: &mut [u8]) -> u8 {
+    write(num, slice)
+}
+
+/// Writes an `i32` to the slice. Returns the number of bytes written (maximum 5 bytes).
+///
+/// **Panics:** if the slice is too small to contain the integer.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn write_i32(num: i32, slice: &mut [u8]) -> u8 {
+    write(num, slice)
+}
+
+/// Writes an `i64` to the slice. Returns the number of bytes written (maximum 10 bytes).
+///
+/// **Panics:** if the slice is too small to contain the integer.
+#[inline]
+#[cfg(any(target_feature = "sse2", doc))]
+#[cfg_attr(rustc_nightly, doc(cfg(target_feature = "sse2")))]
+pub fn write_i64(num: i64, slice: &mut [u8]) -> u8 {
+    write(num, slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_header(field_type: CompactType, field_id: i16, last_field_id: i16, encoded: &[u8]) {
+        let header = FieldHeader {
+            field_type,
+            field_id,
+        };
+
+        let (data, len) = encode_field_header(header, last_field_id);
+        assert_eq!(&data[..len as usize], encoded);
+
+        assert_eq!(
+            decode_field_header(encoded, last_field_id).unwrap(),
+            (Some(header), encoded.len())
+        );
+    }
+
+    #[test]
+    fn field_headers() {
+        check_header(CompactType::I32, 1, 0, &[0x15]);
+        check_header(CompactType::Struct, 15, 0, &[0xFC]);
+        check_header(CompactType::Binary, 16, 0, &[0x08, 0x20]);
+        check_header(CompactType::I64, 2, 5, &[0x06, 0x04]);
+        check_header(CompactType::List, -1, 0, &[0x09, 0x01]);
+        check_header(
+            CompactType::BooleanTrue,
+            i16::MAX,
+            0,
+            &[0x01, 0xFE, 0xFF, 0x03],
+        );
+        check_header(
+            CompactType::Map,
+            i16::MIN,
+            i16::MIN,
+            &[0x0B, 0xFF, 0xFF, 0x03],
+        );
+
+        assert_eq!(decode_field_header(&[STOP, 0xFF], 7).unwrap(), (None, 1));
+    }
+
+    #[test]
+    fn struct_fields() {
+        // fields 1, 2, 20, and 21, followed by the end of the struct
+        let fields = [
+            (CompactType::I16, 1, -5i64),
+            (CompactType::I32, 2, 1337),
+            (CompactType::I64, 20, i64::MIN),
+            (CompactType::I64, 21, i64::MAX),
+        ];
+
+        let mut encoded = [0u8; 64];
+        let mut len = 0;
+        let mut last_field_id = 0;
+        for (field_type, field_id, value) in fields {
+            let (data, size) = encode_field_header(
+                FieldHeader {
+                    field_type,
+                    field_id,
+                },
+                last_field_id,
+            );
+            encoded[len..len + size as usize].copy_from_slice(&data[..size as usize]);
+            len += size as usize;
+            last_field_id = field_id;
+
+            len += match field_type {
+                CompactType::I16 => write_i16(value as i16, &mut encoded[len..]),
+                CompactType::I32 => write_i32(value as i32, &mut encoded[len..]),
+                _ => write_i64(value, &mut encoded[len..]),
+            } as usize;
+        }
+        encoded[len] = STOP;
+        len += 1;
+
+        let mut pos = 0;
+        let mut last_field_id = 0;
+        for (field_type, field_id, value) in fields {
+            let (header, size) = decode_field_header(&encoded[pos..len], last_field_id).unwrap();
+            let header = header.unwrap();
+            assert_eq!(header.field_type, field_type);
+            assert_eq!(header.field_id, field_id);
+            pos += size;
+            last_field_id = field_id;
+
+            let (decoded, size) = match field_type {
+                CompactType::I16 => read_i16(&encoded[pos..len]).map(|r| (r.0 as i64, r.1)),
+                CompactType::I32 => read_i32(&encoded[pos..len]).map(|r| (r.0 as i64, r.1)),
+                _ => read_i64(&encoded[pos..len]),
+            }
+            .unwrap();
+            assert_eq!(decoded, value);
+            pos += size;
+        }
+
+        assert_eq!(
+            decode_field_header(&encoded[pos..len], last_field_id).unwrap(),
+            (None, 1)
+        );
+        assert_eq!(pos + 1, len);
+    }
+
+    #[test]
+    fn integers() {
+        let mut data = [0u8; 10];
+        for num in [0, 1, -1, 63, -64, 64, i16::MIN, i16::MAX] {
+            let len = write_i16(num, &mut data);
+            assert_eq!(
+                read_i16(&data[..len as usize]).unwrap(),
+                (num, len as usize)
+            );
+        }
+        for num in [0, -1, 1 << 20, i32::MIN, i32::MAX] {
+            let len = write_i32(num, &mut data);
+            assert_eq!(
+                read_i32(&data[..len as usize]).unwrap(),
+                (num, len as usize)
+            );
+        }
+        for num in [0, -1, 1 << 40, i64::MIN, i64::MAX] {
+            let len = write_i64(num, &mut data);
+            assert_eq!(
+                read_i64(&data[..len as usize]).unwrap(),
+                (num, len as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            decode_field_header(&[0x1D], 0),
+            Err(VarIntDecodeError::Invalid)
+        ));
+        assert!(matches!(
+            decode_field_header(&[0x15], i16::MAX),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            decode_field_header(&[0x05, 0x80, 0x80, 0x04], 0),
+            Err(VarIntDecodeError::Overflow)
+        ));
+        assert!(matches!(
+            read_i32(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]),
+            Err(VarIntDecodeError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn not_enough_bytes() {
+        assert!(matches!(
+            decode_field_header(&[], 0),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            decode_field_header(&[0x05, 0x80], 0),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+        assert!(matches!(
+            read_i64(&[0x80, 0x80]),
+            Err(VarIntDecodeError::NotEnoughBytes)
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+//! [winnow](https://docs.rs/winnow) parsers for varints.
+//!
+//! The parsers work with any byte stream. With a [`Partial`](::winnow::Partial) stream, they
+//! return an incomplete error with the number of additional bytes needed if the input ends in the
+//! middle of a value. Otherwise, short input is a backtracking error.
+//!
+//! Decoding errors such as [`VarIntDecodeError::Overflow`] are reported through
+//! [`FromExternalError`].
+//!
+//! Requires the `winnow` feature.
+//!
+//! # Examples
+//! ```
+//! use winnow::error::{ErrMode, InputError, Needed};
+//! use winnow::prelude::*;
+//! use winnow::Partial;
+//! use varint_simd::winnow::{length_prefixed, varint, zigzag};
+//!
+//! fn header<'i>(input: &mut &'i [u8]) -> ModalResult<(u32, i64, &'i [u8])> {
+//!     (varint, zigzag, length_prefixed).parse_next(input)
+//! }
+//!
+//! let mut input = &[185, 10, 3, 2, b'h', b'i', 0xFF][..];
+//! assert_eq!(header(&mut input).unwrap(), (1337, -2, &b"hi"[..]));
+//! assert_eq!(input, &[0xFF]);
+//!
+//! let mut partial = Partial::new(&[0x80, 0x80][..]);
+//! assert_eq!(
+//!     varint::<u64, _, ErrMode<InputError<_>>>(&mut partial),
+//!     Err(ErrMode::Incomplete(Needed::new(1)))
+//! );
+//! ```
+
+use crate::num::{SignedVarIntTarget, VarIntTarget};
+use crate::VarIntDecodeError;
+
+use ::winnow::error::{FromExternalError, Needed, ParserError};
+use ::winnow::stream::{AsBytes, Stream, StreamIsPartial};
+use ::winnow::Parser;
+
+/// Parses a single varint.
+#[inline]
+pub fn varint<T, Input, Error>(input: &mut Input) -> Result<T, Error>
+where
+    T: VarIntTarget,
+    Input: StreamIsPartial + Stream<Token = u8> + AsBytes,
+    Error: ParserError<Input> + FromExternalError<Input, VarIntDecodeError>,
+{
+    let bytes = input.as_bytes();
+    let needed = match crate::decode::<T>(bytes) {
+        Ok((num, len)) if len <= bytes.len() => {
+            input.next_slice(len);
+            return Ok(num);
+        }
+        Ok((_, len)) => len - bytes.len(),
+        Err(VarIntDecodeError::NotEnoughBytes) => 1,
+        Err(err) => return Err(Error::from_external_error(input, err)),
+    };
+
+    if <Input as StreamIsPartial>::is_partial_supported() && input.is_partial() {
+        Err(Error::incomplete(input, Needed::new(needed)))
+    } else {
+        Err(Error::from_input(input))
+    }
+}
+
+/// Parses a single varint in ZigZag format.
+///
+/// See also: [`varint`]
+#[inline]
+pub fn zigzag<T, Input, Error>(input: &mut Input) -> Result<T, Error>
+where
+    T: SignedVarIntTarget,
+    Input: StreamIsPartial + Stream<Token = u8> + AsBytes,
+    Error: ParserError<Input> + FromExternalError<Input, VarIntDecodeError>,
+{
+    varint::<T::Unsigned, Input, Error>(input).map(|num| num.unzigzag())
+}
+
+/// Parses a varint length prefix as a `usize`, such as for use with
+/// [`winnow::binary::length_repeat`](::winnow::binary::length_repeat). Lengths that do not fit
+/// in a `usize` are rejected with [`VarIntDecodeError::Overflow`].
+#[inline]
+pub fn varint_len<Input, Error>(input: &mut Input) -> Result<usize, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8> + AsBytes,
+    Error: ParserError<Input> + FromExternalError<Input, VarIntDecodeError>,
+{
+    let len = varint::<u64, Input, Error>(input)?;
+    usize::try_from(len).map_err(|_| Error::from_external_error(input, VarIntDecodeError::Overflow))
+}
+
+/// Parses a slice prefixed with its length as a varint.
+///
+/// See also: [`varint_len`]
+#[inline]
+pub fn length_prefixed<Input, Error>(input: &mut Input) -> Result<<Input as Stream>::Slice, Error>
+where
+    Input: StreamIsPartial + Stream<Token = u8> + AsBytes,
+    Error: ParserError<Input> + FromExternalError<Input, VarIntDecodeError>,
+{
+    let len = varint_len(input)?;
+    ::winnow::token::take(len).parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::winnow::error::{ErrMode, InputError};
+    use ::winnow::Partial;
+
+    type Error<'a> = ErrMode<InputError<Partial<&'a [u8]>>>;
+
+    #[test]
+    fn varints() {
+        for value in [0u64, 1, 127, 128, 16383, 16384, u32::MAX as u64, u64::MAX] {
+            let (data, len) = crate::encode(value);
+            let len = len as usize;
+
+            let mut input = &data[..len + 1];
+            let res: Result<u64, InputError<&[u8]>> = varint(&mut input);
+            assert_eq!(res, Ok(value));
+            assert_eq!(input, &data[len..len + 1]);
+
+            for end in 0..len {
+                let mut input = Partial::new(&data[..end]);
+                let res: Result<u64, Error> = varint(&mut input);
+                assert_eq!(res, Err(ErrMode::Incomplete(Needed::new(1))));
+
+                let mut input = &data[..end];
+                let res: Result<u64, InputError<&[u8]>> = varint(&mut input);
+                assert!(res.is_err());
+            }
+        }
+
+        let res: Result<i32, InputError<&[u8]>> = zigzag(&mut &[0x03][..]);
+        assert_eq!(res, Ok(-2));
+        let res: Result<i8, InputError<&[u8]>> = zigzag(&mut &[0xFF, 0x01][..]);
+        assert_eq!(res, Ok(i8::MIN));
+    }
+
+    #[test]
+    fn length_prefixes() {
+        let mut input = &[3, b'a', b'b', b'c', 7][..];
+        let res: Result<&[u8], InputError<&[u8]>> = length_prefixed(&mut input);
+        assert_eq!(res, Ok(&b"abc"[..]));
+        assert_eq!(input, &[7]);
+
+        let mut input = Partial::new(&[3, b'a'][..]);
+        let res: Result<&[u8], Error> = length_prefixed(&mut input);
+        assert_eq!(res, Err(ErrMode::Incomplete(Needed::new(2))));
+
+        let res: Result<Vec<u16>, InputError<&[u8]>> =
+            ::winnow::binary::length_repeat(varint_len, varint::<u16, _, _>)
+                .parse_next(&mut &[2, 0x80, 0x01, 0x05][..]);
+        assert_eq!(res, Ok(vec![128, 5]));
+    }
+
+    #[test]
+    fn errors() {
+        let mut input = &[0xFF, 0xFF, 0xFF, 0xFF, 0x1F][..];
+        let res: Result<u32, InputError<&[u8]>> = varint(&mut input);
+        assert!(res.is_err());
+    }
+}